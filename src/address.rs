@@ -1,87 +1,262 @@
+use std::fmt::Display;
+
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::country::CountryCode;
+
 /// Represents an address
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(from = "AddressRecord")]
 pub struct Address {
+    pub country: CountryCode,
     pub city: String,
     pub street: String,
+    /// Free-form house number, e.g. `12a` or `1234/5b`
+    pub house_number: String,
+    /// Second line of the street address, e.g. the building or the floor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_street: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub po_box: Option<String>,
     pub postal_code: String,
-    pub house_number: u32,
-    pub orientation_number: Option<u32>,
+}
+
+/// House number stored by older versions as a number
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HouseNumber {
+    Number(u32),
+    Text(String),
+}
+
+impl Display for HouseNumber {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            HouseNumber::Number(number) => number.fmt(f),
+            HouseNumber::Text(text) => f.write_str(text),
+        }
+    }
+}
+
+/// Stored form of the address, also accepts addresses saved before the country
+/// and the free-form house numbers existed
+#[derive(Deserialize)]
+struct AddressRecord {
+    #[serde(default)]
+    country: CountryCode,
+    city: String,
+    street: String,
+    house_number: HouseNumber,
+    #[serde(default)]
+    orientation_number: Option<HouseNumber>,
+    #[serde(default)]
+    additional_street: Option<String>,
+    #[serde(default)]
+    po_box: Option<String>,
+    postal_code: String,
+}
+
+impl From<AddressRecord> for Address {
+    fn from(record: AddressRecord) -> Self {
+        let house_number = match record.orientation_number {
+            Some(orientation_number) => {
+                format!(
+                    "{}/{}",
+                    record.house_number, orientation_number
+                )
+            }
+            None => {
+                record
+                    .house_number
+                    .to_string()
+            }
+        };
+
+        Self {
+            country: record.country,
+            city: record.city,
+            street: record.street,
+            house_number,
+            additional_street: record.additional_street,
+            po_box: record.po_box,
+            postal_code: record.postal_code,
+        }
+    }
 }
 
 impl Address {
     pub fn new(
-        city: String,
-        street: String,
-        postal_code: String,
-        house_number: u32,
-        orientation_number: Option<u32>,
+        country: CountryCode,
+        city: impl Into<String>,
+        street: impl Into<String>,
+        postal_code: impl Into<String>,
+        house_number: impl Into<String>,
     ) -> Self {
         Self {
-            city,
-            street,
-            postal_code,
-            house_number,
-            orientation_number,
+            country,
+            city: city.into(),
+            street: street.into(),
+            house_number: house_number.into(),
+            additional_street: None,
+            po_box: None,
+            postal_code: postal_code.into(),
         }
     }
 
-    /// Get the first line of the address
+    /// Get the first line of the address, the house number is placed by the customs of the country
     pub fn get_first_line(&self) -> String {
-        let mut result = format!(
-            "{} {}",
-            self.street, self.house_number
-        );
+        let house_number = self
+            .house_number
+            .trim();
 
-        if let Some(orientation_number) = self.orientation_number {
-            result.push_str(
-                &format!(
-                    "/{}",
-                    orientation_number
-                ),
-            );
+        if house_number.is_empty() {
+            return self
+                .street
+                .clone();
+        }
+
+        match self.country {
+            CountryCode::Fr | CountryCode::Ie | CountryCode::Lu | CountryCode::Mt => {
+                format!(
+                    "{} {}",
+                    house_number, self.street
+                )
+            }
+            CountryCode::Es | CountryCode::It => {
+                format!(
+                    "{}, {}",
+                    self.street, house_number
+                )
+            }
+            _ => {
+                format!(
+                    "{} {}",
+                    self.street, house_number
+                )
+            }
         }
+    }
+
+    /// Returns the postal code in the national format, e.g. `120 00`, `00-950` or `1012 AB`
+    pub fn formatted_postal_code(&self) -> String {
+        let code: String = self
+            .postal_code
+            .chars()
+            .filter(|x| !x.is_whitespace())
+            .collect();
 
-        result
+        let digits = code
+            .chars()
+            .all(|x| x.is_ascii_digit());
+
+        match self.country {
+            CountryCode::Cz | CountryCode::Sk | CountryCode::Se | CountryCode::Gr
+                if digits && code.len() == 5 =>
+            {
+                format!(
+                    "{} {}",
+                    &code[..3],
+                    &code[3..]
+                )
+            }
+            CountryCode::Pl if digits && code.len() == 5 => {
+                format!(
+                    "{}-{}",
+                    &code[..2],
+                    &code[2..]
+                )
+            }
+            CountryCode::Nl if code.is_ascii() && code.len() == 6 => {
+                format!(
+                    "{} {}",
+                    &code[..4],
+                    code[4..].to_uppercase()
+                )
+            }
+            _ => {
+                self.postal_code
+                    .trim()
+                    .to_string()
+            }
+        }
     }
 
-    /// Get the second line of the address
+    /// Get the second line of the address, the postal code and the city
     pub fn get_second_line(&self) -> String {
-        if self
-            .postal_code
-            .len()
-            == 6
+        let postal_code = self.formatted_postal_code();
+
+        let line = match self.country {
+            CountryCode::Ie | CountryCode::Mt => {
+                format!(
+                    "{} {}",
+                    self.city, postal_code
+                )
+            }
+            _ => {
+                format!(
+                    "{} {}",
+                    postal_code, self.city
+                )
+            }
+        };
+
+        line.trim()
+            .to_string()
+    }
+
+    /// Returns the lines of the address as printed on an envelope, without the country
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        if !self
+            .street
+            .trim()
+            .is_empty()
         {
-            return self
-                .postal_code
-                .clone();
+            lines.push(self.get_first_line());
         }
 
-        let mut number_str = format!(
-            "{:05}",
-            self.postal_code
+        lines.extend(
+            self.additional_street
+                .clone(),
         );
-        number_str.insert(
-            3, ' ',
-        );
-        number_str.push(' ');
-        number_str.push_str(&self.city);
-        number_str
+
+        if let Some(po_box) = &self.po_box {
+            let label = match self.country {
+                CountryCode::De | CountryCode::At => "Postfach",
+                CountryCode::Fr => "BP",
+                _ => "P. O. Box",
+            };
+
+            lines.push(
+                format!(
+                    "{} {}",
+                    label, po_box
+                ),
+            );
+        }
+
+        lines.push(self.get_second_line());
+        lines
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn test_address() {
-        let address = super::Address::new(
-            "Praha".to_string(),
-            "Husova".to_string(),
-            "12000".to_string(),
-            123,
-            None,
+        let address = Address::new(
+            CountryCode::Cz,
+            "Praha",
+            "Husova",
+            "12000",
+            "123",
         );
 
         assert_eq!(
@@ -93,4 +268,66 @@ mod tests {
             "120 00 Praha"
         );
     }
+
+    #[test]
+    fn test_foreign_address() {
+        let mut address = Address::new(
+            CountryCode::Fr,
+            "Paris",
+            "Rue de Rivoli",
+            "75001",
+            "12a",
+        );
+        address.po_box = Some("123".to_string());
+
+        assert_eq!(
+            address.lines(),
+            vec![
+                "12a Rue de Rivoli",
+                "BP 123",
+                "75001 Paris",
+            ]
+        );
+
+        let address = Address::new(
+            CountryCode::Pl,
+            "Warszawa",
+            "Marszałkowska",
+            "00950",
+            "1234/5b",
+        );
+
+        assert_eq!(
+            address.lines(),
+            vec![
+                "Marszałkowska 1234/5b",
+                "00-950 Warszawa",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_legacy_address() {
+        let address: Address = serde_json::from_str(
+            r#"{
+                "city": "Praha",
+                "street": "Husova",
+                "postal_code": "12000",
+                "house_number": 123,
+                "orientation_number": 4
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            address,
+            Address::new(
+                CountryCode::Cz,
+                "Praha",
+                "Husova",
+                "12000",
+                "123/4",
+            )
+        );
+    }
 }
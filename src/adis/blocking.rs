@@ -0,0 +1,223 @@
+use reqwest::header::CONTENT_TYPE;
+
+use super::client::parse_payers;
+use super::client::request_body;
+use super::client::validate;
+use super::client::MAX_BATCH;
+use super::client::SOAP_ACTION;
+use super::AdisConfig;
+use super::Error;
+use super::VatPayer;
+use crate::vat_number::VatNumber;
+
+/// Blocking client of the Czech VAT register
+#[derive(Debug, Clone)]
+pub struct AdisClient {
+    config: AdisConfig,
+    client: reqwest::blocking::Client,
+}
+
+impl AdisClient {
+    pub fn new(config: AdisConfig) -> Result<Self, Error> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout)
+            .build()
+            .map_err(Error::TransportError)?;
+
+        Ok(
+            Self {
+                config,
+                client,
+            },
+        )
+    }
+
+    /// Fetches the status and the published accounts of the payer
+    pub fn check(
+        &self,
+        number: &VatNumber,
+    ) -> Result<VatPayer, Error> {
+        let mut payers = self.check_many(std::slice::from_ref(number))?;
+
+        Ok(payers.remove(0))
+    }
+
+    /// Fetches the records of all the payers, in the order of the numbers.
+    ///
+    /// The numbers are sent in batches of 100, the most ADIS accepts in one request.
+    /// Non-Czech numbers are refused with `Error::UnsupportedCountry` before anything is sent.
+    pub fn check_many(
+        &self,
+        numbers: &[VatNumber],
+    ) -> Result<Vec<VatPayer>, Error> {
+        validate(numbers)?;
+
+        let mut payers = Vec::with_capacity(numbers.len());
+
+        for batch in numbers.chunks(MAX_BATCH) {
+            let request = request_body(batch);
+
+            let body = self
+                .config
+                .retry()
+                .send_blocking(
+                    || {
+                        self.client
+                            .post(
+                                &self
+                                    .config
+                                    .base_url,
+                            )
+                            .header(
+                                CONTENT_TYPE,
+                                "text/xml; charset=utf-8",
+                            )
+                            .header(
+                                "SOAPAction",
+                                SOAP_ACTION,
+                            )
+                            .body(request.clone())
+                    },
+                    None,
+                    None,
+                )?;
+
+            payers.extend(
+                parse_payers(
+                    batch, &body,
+                )?,
+            );
+        }
+
+        Ok(payers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adis::tests::RESPONSE;
+    use crate::adis::Account;
+    use crate::adis::PayerStatus;
+    use crate::http::tests::serve;
+
+    #[test]
+    fn test_check_many() {
+        let client = AdisClient::new(
+            AdisConfig {
+                base_url: serve(
+                    vec![
+                        (
+                            200, RESPONSE,
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let payers = client
+            .check_many(
+                &[
+                    "CZ25596641"
+                        .parse()
+                        .unwrap(),
+                    "CZ27082440"
+                        .parse()
+                        .unwrap(),
+                    "CZ25123891"
+                        .parse()
+                        .unwrap(),
+                    "CZ25596641"
+                        .parse()
+                        .unwrap(),
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(
+            payers
+                .iter()
+                .map(|x| x.status)
+                .collect::<Vec<_>>(),
+            vec![
+                PayerStatus::Unreliable,
+                PayerStatus::Reliable,
+                PayerStatus::NotFound,
+                PayerStatus::Unreliable,
+            ]
+        );
+        assert_eq!(
+            payers[1].accounts[0].account,
+            Account::Standard {
+                prefix: "19".to_string(),
+                number: "2000145399".to_string(),
+                bank_code: "0800".to_string(),
+            }
+        );
+        assert!(
+            payers[1].is_published(
+                &"SK3112000000198742637541"
+                    .parse()
+                    .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_service_error() {
+        let client = AdisClient::new(
+            AdisConfig {
+                base_url: serve(
+                    vec![
+                        (
+                            200,
+                            r#"<soapenv:Envelope xmlns:soapenv="http://schemas.xmlsoap.org/soap/envelope/"><soapenv:Body><StatusNespolehlivyPlatceResponse xmlns="http://adis.mfcr.cz/rozhraniCRPDPH/"><status statusCode="2" statusText="Chybný formát DIČ"/></StatusNespolehlivyPlatceResponse></soapenv:Body></soapenv:Envelope>"#,
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert!(
+            matches!(
+                client.check(
+                    &"CZ27082440"
+                        .parse()
+                        .unwrap()
+                ),
+                Err(Error::ServiceError(_))
+            )
+        );
+    }
+    #[test]
+    fn test_unsupported_country() {
+        let client = AdisClient::new(
+            AdisConfig {
+                base_url: serve(vec![]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert!(
+            matches!(
+                client.check_many(
+                    &[
+                        "CZ27082440"
+                            .parse()
+                            .unwrap(),
+                        "SK2020317068"
+                            .parse()
+                            .unwrap(),
+                    ]
+                ),
+                Err(Error::UnsupportedCountry(_))
+            )
+        );
+    }
+}
@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use super::Account;
+use super::AdisConfig;
+use super::Error;
+use super::PayerStatus;
+use super::PublishedAccount;
+use super::VatPayer;
+use crate::country::CountryCode;
+use crate::http::Retry;
+use crate::vat_number::VatNumber;
+
+const NAMESPACE: &str = "http://adis.mfcr.cz/rozhraniCRPDPH/";
+
+pub(super) const SOAP_ACTION: &str =
+    "http://adis.mfcr.cz/rozhraniCRPDPH/getStatusNespolehlivyPlatce";
+
+/// ADIS accepts at most 100 DIČ in one request
+pub(super) const MAX_BATCH: usize = 100;
+
+#[derive(Debug, Deserialize)]
+struct SoapEnvelope {
+    #[serde(rename = "Body")]
+    body: SoapBody,
+}
+
+#[derive(Debug, Deserialize)]
+struct SoapBody {
+    #[serde(rename = "StatusNespolehlivyPlatceResponse")]
+    response: StatusResponse,
+}
+
+#[derive(Debug, Deserialize)]
+struct StatusResponse {
+    status: ResponseStatus,
+    #[serde(
+        rename = "statusPlatceDPH",
+        default
+    )]
+    payers: Vec<PayerRecord>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseStatus {
+    #[serde(rename = "@statusCode")]
+    code: u32,
+    #[serde(rename = "@statusText")]
+    text: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct PayerRecord {
+    #[serde(rename = "@dic")]
+    dic: String,
+    /// `ANO`, `NE` or `NENALEZEN`
+    #[serde(rename = "@nespolehlivyPlatce")]
+    unreliable: String,
+    #[serde(rename = "@cisloFu")]
+    tax_office: Option<String>,
+    #[serde(rename = "@datumZverejneniNespolehlivosti")]
+    unreliable_since: Option<NaiveDate>,
+    #[serde(rename = "zverejneneUcty")]
+    accounts: Option<AccountsRecord>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct AccountsRecord {
+    #[serde(
+        rename = "ucet",
+        default
+    )]
+    accounts: Vec<AccountRecord>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct AccountRecord {
+    #[serde(rename = "@datumZverejneni")]
+    published_at: Option<NaiveDate>,
+    #[serde(rename = "standardniUcet")]
+    standard: Option<StandardAccountRecord>,
+    #[serde(rename = "nestandardniUcet")]
+    non_standard: Option<NonStandardAccountRecord>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct StandardAccountRecord {
+    #[serde(rename = "@predcisli")]
+    prefix: Option<String>,
+    #[serde(rename = "@cislo")]
+    number: String,
+    #[serde(rename = "@kodBanky")]
+    bank_code: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct NonStandardAccountRecord {
+    #[serde(rename = "@cislo")]
+    number: String,
+}
+
+impl AdisConfig {
+    pub(super) fn retry(&self) -> Retry {
+        Retry {
+            max_retries: self.max_retries,
+            backoff: self.backoff,
+            max_retry_after: self.max_retry_after,
+        }
+    }
+}
+
+/// ADIS only knows Czech payers, the prefix is not sent so other numbers have to be refused beforehand
+pub(super) fn validate(numbers: &[VatNumber]) -> Result<(), Error> {
+    match numbers
+        .iter()
+        .find(|x| x.country() != CountryCode::Cz)
+    {
+        Some(number) => Err(Error::UnsupportedCountry(number.to_string())),
+        None => Ok(()),
+    }
+}
+
+/// Builds the `getStatusNespolehlivyPlatce` request, DIČ are sent without the country prefix
+pub(super) fn request_body(numbers: &[VatNumber]) -> String {
+    let dic: String = numbers
+        .iter()
+        .map(
+            |x| {
+                format!(
+                    "<dic>{}</dic>",
+                    x.number()
+                )
+            },
+        )
+        .collect();
+
+    format!(
+        r#"<soapenv:Envelope xmlns:soapenv="http://schemas.xmlsoap.org/soap/envelope/"><soapenv:Body><StatusNespolehlivyPlatceRequest xmlns="{}">{}</StatusNespolehlivyPlatceRequest></soapenv:Body></soapenv:Envelope>"#,
+        NAMESPACE, dic
+    )
+}
+
+fn to_account(record: AccountRecord) -> Option<PublishedAccount> {
+    let account = match (
+        record.standard,
+        record.non_standard,
+    ) {
+        (Some(standard), _) => {
+            Account::Standard {
+                prefix: standard
+                    .prefix
+                    .unwrap_or_default(),
+                number: standard.number,
+                bank_code: standard.bank_code,
+            }
+        }
+        (None, Some(non_standard)) => Account::NonStandard(non_standard.number),
+        (None, None) => return None,
+    };
+
+    Some(
+        PublishedAccount {
+            account,
+            published_at: record.published_at,
+        },
+    )
+}
+
+/// Returns one record per number, in the order of the numbers
+pub(super) fn parse_payers(
+    numbers: &[VatNumber],
+    body: &str,
+) -> Result<Vec<VatPayer>, Error> {
+    let envelope: SoapEnvelope = quick_xml::de::from_str(body).map_err(Error::XmlError)?;
+    let response = envelope
+        .body
+        .response;
+
+    if response
+        .status
+        .code
+        != 0
+    {
+        return Err(
+            Error::ServiceError(
+                response
+                    .status
+                    .text,
+            ),
+        );
+    }
+
+    let records: HashMap<String, PayerRecord> = response
+        .payers
+        .into_iter()
+        .map(
+            |x| {
+                (
+                    x.dic
+                        .clone(),
+                    x,
+                )
+            },
+        )
+        .collect();
+
+    Ok(
+        numbers
+            .iter()
+            .map(
+                |number| {
+                    let Some(record) = records
+                        .get(number.number())
+                        .cloned()
+                    else {
+                        return VatPayer {
+                            vat_number: number.clone(),
+                            status: PayerStatus::NotFound,
+                            unreliable_since: None,
+                            tax_office: None,
+                            accounts: Vec::new(),
+                        };
+                    };
+
+                    VatPayer {
+                        vat_number: number.clone(),
+                        status: match record
+                            .unreliable
+                            .as_str()
+                        {
+                            "ANO" => PayerStatus::Unreliable,
+                            "NE" => PayerStatus::Reliable,
+                            _ => PayerStatus::NotFound,
+                        },
+                        unreliable_since: record.unreliable_since,
+                        tax_office: record.tax_office,
+                        accounts: record
+                            .accounts
+                            .map(|x| x.accounts)
+                            .unwrap_or_default()
+                            .into_iter()
+                            .filter_map(to_account)
+                            .collect(),
+                    }
+                },
+            )
+            .collect(),
+    )
+}
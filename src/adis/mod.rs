@@ -0,0 +1,243 @@
+use std::time::Duration;
+
+use chrono::NaiveDate;
+use iban::Iban;
+use iban::IbanLike;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::invoice::Invoice;
+use crate::vat_number::VatNumber;
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(
+    any(
+        feature = "blocking",
+        feature = "async"
+    )
+)]
+mod client;
+#[cfg(feature = "async")]
+pub mod nonblocking;
+
+#[cfg(feature = "blocking")]
+pub use blocking::AdisClient;
+#[cfg(feature = "async")]
+pub use nonblocking::AsyncAdisClient;
+
+pub use crate::http::Error;
+
+/// Address of the SOAP service of the Czech VAT register (registr plátců DPH) in ADIS
+pub const DEFAULT_BASE_URL: &str =
+    "https://adisrws.mfcr.cz/adistc/axis2/services/rozhraniCRPDPH.rozhraniCRPDPHSOAP";
+
+/// Status of the subject in the VAT register
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PayerStatus {
+    /// Spolehlivý plátce
+    Reliable,
+    /// Nespolehlivý plátce, the recipient is liable for the VAT
+    Unreliable,
+    /// The DIČ isn't registered for VAT in the Czech Republic
+    NotFound,
+}
+
+/// Bank account published in the VAT register
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum Account {
+    /// Czech account given by the prefix, the number and the bank code
+    Standard {
+        prefix: String,
+        number: String,
+        bank_code: String,
+    },
+    /// Account in any other format, usually IBAN of a foreign account
+    NonStandard(String),
+}
+
+impl Account {
+    /// Checks whether the IBAN denotes this account.
+    pub fn matches(
+        &self,
+        iban: &Iban,
+    ) -> bool {
+        let electronic = iban.electronic_str();
+
+        match self {
+            Account::Standard {
+                prefix,
+                number,
+                bank_code,
+            } => {
+                electronic.starts_with("CZ")
+                    && electronic[4..]
+                        == format!(
+                            "{:0>4}{:0>6}{:0>10}",
+                            bank_code, prefix, number
+                        )
+            }
+            Account::NonStandard(number) => {
+                number
+                    .chars()
+                    .filter(|x| !x.is_whitespace())
+                    .collect::<String>()
+                    .eq_ignore_ascii_case(electronic)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct PublishedAccount {
+    pub account: Account,
+    pub published_at: Option<NaiveDate>,
+}
+
+/// Record of the subject in the VAT register
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct VatPayer {
+    pub vat_number: VatNumber,
+    pub status: PayerStatus,
+    /// Date when the payer was marked as unreliable
+    pub unreliable_since: Option<NaiveDate>,
+    /// Number of the tax office administering the payer
+    pub tax_office: Option<String>,
+    pub accounts: Vec<PublishedAccount>,
+}
+
+impl VatPayer {
+    pub fn is_unreliable(&self) -> bool {
+        self.status == PayerStatus::Unreliable
+    }
+
+    /// Returns true if the account is published in the VAT register.
+    pub fn is_published(
+        &self,
+        iban: &Iban,
+    ) -> bool {
+        self.accounts
+            .iter()
+            .any(
+                |x| {
+                    x.account
+                        .matches(iban)
+                },
+            )
+    }
+
+    /// Checks that paying the invoice doesn't make us liable for the VAT of the contractor:
+    /// the contractor is a reliable payer and the account of the invoice is published.
+    ///
+    /// The record has to be the one of the contractor of the invoice.
+    pub fn is_safe_to_pay(
+        &self,
+        invoice: &Invoice,
+    ) -> bool {
+        self.status == PayerStatus::Reliable
+            && invoice
+                .iban()
+                .is_some_and(|x| self.is_published(x))
+    }
+}
+
+/// Configuration of the ADIS client
+#[derive(Debug, Clone)]
+pub struct AdisConfig {
+    pub base_url: String,
+    /// Timeout of the whole request
+    pub timeout: Duration,
+    pub connect_timeout: Duration,
+    /// How many times a request is retried after a 429 or 5xx response
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled with every next one
+    pub backoff: Duration,
+    /// Longest `Retry-After` the client waits for, a longer one fails with `Error::RateLimited`
+    pub max_retry_after: Duration,
+}
+
+impl Default for AdisConfig {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            timeout: Duration::from_secs(30),
+            connect_timeout: Duration::from_secs(5),
+            max_retries: 3,
+            backoff: Duration::from_millis(500),
+            max_retry_after: Duration::from_secs(60),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "blocking")]
+    pub(crate) const RESPONSE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<soapenv:Envelope xmlns:soapenv="http://schemas.xmlsoap.org/soap/envelope/">
+    <soapenv:Body>
+        <StatusNespolehlivyPlatceResponse xmlns="http://adis.mfcr.cz/rozhraniCRPDPH/">
+            <status odpovedGenerovana="2024-03-01" statusCode="0" statusText="OK"/>
+            <statusPlatceDPH dic="27082440" nespolehlivyPlatce="NE" cisloFu="451">
+                <zverejneneUcty>
+                    <ucet datumZverejneni="2013-04-01">
+                        <standardniUcet predcisli="19" cislo="2000145399" kodBanky="0800"/>
+                    </ucet>
+                    <ucet datumZverejneni="2019-10-01">
+                        <nestandardniUcet cislo="SK3112000000198742637541"/>
+                    </ucet>
+                </zverejneneUcty>
+            </statusPlatceDPH>
+            <statusPlatceDPH dic="25596641" nespolehlivyPlatce="ANO" cisloFu="453" datumZverejneniNespolehlivosti="2020-05-04"/>
+        </StatusNespolehlivyPlatceResponse>
+    </soapenv:Body>
+</soapenv:Envelope>"#;
+
+    #[test]
+    fn test_published_accounts() {
+        let payer = VatPayer {
+            vat_number: "CZ27082440"
+                .parse()
+                .unwrap(),
+            status: PayerStatus::Reliable,
+            unreliable_since: None,
+            tax_office: None,
+            accounts: vec![
+                PublishedAccount {
+                    account: Account::Standard {
+                        prefix: "19".to_string(),
+                        number: "2000145399".to_string(),
+                        bank_code: "0800".to_string(),
+                    },
+                    published_at: None,
+                },
+                PublishedAccount {
+                    account: Account::NonStandard("SK31 1200 0000 1987 4263 7541".to_string()),
+                    published_at: None,
+                },
+            ],
+        };
+
+        for iban in [
+            "CZ6508000000192000145399",
+            "SK3112000000198742637541",
+        ] {
+            assert!(
+                payer.is_published(
+                    &iban
+                        .parse()
+                        .unwrap()
+                )
+            );
+        }
+
+        assert!(
+            !payer.is_published(
+                &"CZ5508000000001234567899"
+                    .parse()
+                    .unwrap()
+            )
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use reqwest::header::CONTENT_TYPE;
+
+use super::client::parse_payers;
+use super::client::request_body;
+use super::client::validate;
+use super::client::MAX_BATCH;
+use super::client::SOAP_ACTION;
+use super::AdisConfig;
+use super::Error;
+use super::VatPayer;
+use crate::vat_number::VatNumber;
+
+/// Asynchronous client of the Czech VAT register, for use inside a tokio runtime
+#[derive(Debug, Clone)]
+pub struct AsyncAdisClient {
+    config: AdisConfig,
+    client: reqwest::Client,
+}
+
+impl AsyncAdisClient {
+    pub fn new(config: AdisConfig) -> Result<Self, Error> {
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout)
+            .build()
+            .map_err(Error::TransportError)?;
+
+        Ok(
+            Self {
+                config,
+                client,
+            },
+        )
+    }
+
+    /// Fetches the status and the published accounts of the payer
+    pub async fn check(
+        &self,
+        number: &VatNumber,
+    ) -> Result<VatPayer, Error> {
+        let mut payers = self
+            .check_many(std::slice::from_ref(number))
+            .await?;
+
+        Ok(payers.remove(0))
+    }
+
+    /// Fetches the records of all the payers, in the order of the numbers.
+    ///
+    /// The numbers are sent in batches of 100, the most ADIS accepts in one request.
+    /// Non-Czech numbers are refused with `Error::UnsupportedCountry` before anything is sent.
+    pub async fn check_many(
+        &self,
+        numbers: &[VatNumber],
+    ) -> Result<Vec<VatPayer>, Error> {
+        validate(numbers)?;
+
+        let mut payers = Vec::with_capacity(numbers.len());
+
+        for batch in numbers.chunks(MAX_BATCH) {
+            let request = request_body(batch);
+
+            let body = self
+                .config
+                .retry()
+                .send_async(
+                    || {
+                        self.client
+                            .post(
+                                &self
+                                    .config
+                                    .base_url,
+                            )
+                            .header(
+                                CONTENT_TYPE,
+                                "text/xml; charset=utf-8",
+                            )
+                            .header(
+                                "SOAPAction",
+                                SOAP_ACTION,
+                            )
+                            .body(request.clone())
+                    },
+                    None,
+                    None,
+                )
+                .await?;
+
+            payers.extend(
+                parse_payers(
+                    batch, &body,
+                )?,
+            );
+        }
+
+        Ok(payers)
+    }
+}
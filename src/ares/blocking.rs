@@ -0,0 +1,584 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::thread;
+
+use reqwest::header::CONTENT_TYPE;
+
+use super::client::to_entity;
+use super::client::validate;
+use super::AresConfig;
+use super::AresSearch;
+use super::AresSearchResult;
+use super::AresSubject;
+use super::Error;
+use crate::entity::eu::Entity;
+use crate::http::RateLimiter;
+use crate::registration_number::RegistrationNumber;
+
+/// Blocking client of the ARES registry
+#[derive(Debug, Clone)]
+pub struct AresClient {
+    config: AresConfig,
+    client: reqwest::blocking::Client,
+}
+
+impl AresClient {
+    pub fn new(config: AresConfig) -> Result<Self, Error> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout)
+            .build()
+            .map_err(Error::TransportError)?;
+
+        Ok(
+            Self {
+                config,
+                client,
+            },
+        )
+    }
+
+    /// Fetches the full record of the subject from ARES registry, or from the cache if configured.
+    ///
+    /// Non-Czech numbers are refused with `Error::UnsupportedCountry` before anything is sent.
+    pub fn fetch_subject(
+        &self,
+        number: &RegistrationNumber,
+    ) -> Result<AresSubject, Error> {
+        self.fetch_subject_limited(
+            number, None,
+        )
+    }
+
+    /// Fetches the subject, every request sent to ARES waits for the `limiter` first
+    fn fetch_subject_limited(
+        &self,
+        number: &RegistrationNumber,
+        limiter: Option<&RateLimiter>,
+    ) -> Result<AresSubject, Error> {
+        validate(number)?;
+
+        let url = self
+            .config
+            .url(
+                &format!(
+                    "ekonomicke-subjekty/{}",
+                    number.get()
+                ),
+            );
+
+        let cached = self
+            .config
+            .cached(number);
+
+        if let Some(entry) = &cached {
+            if entry.is_fresh(
+                self.config
+                    .cache_ttl,
+            ) {
+                return Ok(
+                    entry
+                        .subject
+                        .clone(),
+                );
+            }
+        }
+
+        let body = self
+            .config
+            .retry()
+            .send_blocking(
+                || {
+                    self.client
+                        .get(&url)
+                },
+                Some(number),
+                limiter,
+            );
+
+        self.config
+            .settle(
+                number, body, cached,
+            )
+    }
+
+    /// Searches subjects by the business name, returns one page of the results
+    pub fn search(
+        &self,
+        search: &AresSearch,
+    ) -> Result<AresSearchResult, Error> {
+        let url = self
+            .config
+            .url("ekonomicke-subjekty/vyhledat");
+        let request = serde_json::to_string(&search.request()).map_err(Error::JsonError)?;
+
+        let body = self
+            .config
+            .retry()
+            .send_blocking(
+                || {
+                    self.client
+                        .post(&url)
+                        .header(
+                            CONTENT_TYPE,
+                            "application/json",
+                        )
+                        .body(request.clone())
+                },
+                None,
+                None,
+            )?;
+
+        serde_json::from_str(&body).map_err(Error::JsonError)
+    }
+
+    /// Fetches the subject from ARES registry and converts it into an entity
+    pub fn fetch(
+        &self,
+        number: RegistrationNumber,
+    ) -> Result<Entity, Error> {
+        let subject = self.fetch_subject(&number)?;
+
+        to_entity(
+            number, subject,
+        )
+    }
+
+    /// Fetches many subjects at once with bounded concurrency.
+    ///
+    /// Every number gets its own result, so a single failed lookup doesn't abort the batch.
+    /// Repeated numbers are fetched once, cached subjects don't wait for the rate limit.
+    pub fn fetch_many(
+        &self,
+        numbers: impl IntoIterator<Item = RegistrationNumber>,
+    ) -> HashMap<RegistrationNumber, Result<Entity, Error>> {
+        let mut seen = HashSet::new();
+        let queue = Mutex::new(
+            numbers
+                .into_iter()
+                .filter(|x| seen.insert(x.clone()))
+                .collect::<Vec<_>>()
+                .into_iter(),
+        );
+        let results = Mutex::new(HashMap::new());
+        let limiter = RateLimiter::new(
+            self.config
+                .request_interval,
+        );
+
+        thread::scope(
+            |scope| {
+                for _ in 0..self
+                    .config
+                    .concurrency
+                    .max(1)
+                {
+                    scope.spawn(
+                        || {
+                            loop {
+                                let Some(number) = queue
+                                    .lock()
+                                    .unwrap_or_else(|e| e.into_inner())
+                                    .next()
+                                else {
+                                    break;
+                                };
+
+                                let result = self
+                                    .fetch_subject_limited(
+                                        &number,
+                                        Some(&limiter),
+                                    )
+                                    .and_then(
+                                        |subject| {
+                                            to_entity(
+                                                number.clone(),
+                                                subject,
+                                            )
+                                        },
+                                    );
+
+                                results
+                                    .lock()
+                                    .unwrap_or_else(|e| e.into_inner())
+                                    .insert(
+                                        number, result,
+                                    );
+                            }
+                        },
+                    );
+                }
+            },
+        );
+
+        results
+            .into_inner()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Fetches data from ARES registry using the default configuration
+pub fn fetch_from_ares(number: RegistrationNumber) -> Result<Entity, Error> {
+    AresClient::new(AresConfig::default())?.fetch(number)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+    use std::time::SystemTime;
+
+    use super::*;
+    use crate::ares::tests::SUBJECT;
+    use crate::ares::AresCache;
+    use crate::ares::CacheEntry;
+    use crate::ares::MemoryCache;
+    use crate::ares::RegistrationStatus;
+    use crate::http::tests::serve;
+
+    fn client(base_url: String) -> AresClient {
+        AresClient::new(
+            AresConfig {
+                base_url,
+                max_retries: 2,
+                backoff: Duration::from_millis(1),
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_fetch() {
+        let client = client(
+            serve(
+                vec![
+                    (
+                        503, "",
+                    ),
+                    (
+                        200, SUBJECT,
+                    ),
+                ],
+            ),
+        );
+
+        let entity = client
+            .fetch(
+                "27082440"
+                    .parse()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            entity.name,
+            "Alza.cz a.s."
+        );
+    }
+
+    #[test]
+    fn test_fetch_subject() {
+        let client = client(
+            serve(
+                vec![
+                    (
+                        200, SUBJECT,
+                    ),
+                ],
+            ),
+        );
+
+        let subject = client
+            .fetch_subject(
+                &"27082440"
+                    .parse()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            subject
+                .legal_form
+                .as_deref(),
+            Some("121")
+        );
+        assert_eq!(
+            subject.created_at,
+            chrono::NaiveDate::from_ymd_opt(2003, 8, 26)
+        );
+        assert!(subject.is_active());
+        assert!(subject.is_vat_payer());
+        assert_eq!(
+            subject
+                .list_of_registrations
+                .rpsh,
+            RegistrationStatus::NonExistent
+        );
+    }
+
+    #[test]
+    fn test_search() {
+        let client = client(
+            serve(
+                vec![
+                    (
+                        200,
+                        r#"{
+                            "pocetCelkem": 1,
+                            "ekonomickeSubjekty": [
+                                {
+                                    "ico": "27082440",
+                                    "obchodniJmeno": "Alza.cz a.s.",
+                                    "sidlo": {
+                                        "nazevObce": "Praha",
+                                        "textovaAdresa": "Jankovcova 1522/53, Holešovice, 17000 Praha 7"
+                                    },
+                                    "dic": "CZ27082440"
+                                }
+                            ]
+                        }"#,
+                    ),
+                ],
+            ),
+        );
+
+        let result = client
+            .search(
+                &AresSearch::new(
+                    "Alza",
+                    Some("Praha"),
+                ),
+            )
+            .unwrap();
+
+        assert_eq!(
+            result.total,
+            1
+        );
+        assert_eq!(
+            result.subjects[0]
+                .registration_number()
+                .unwrap()
+                .get(),
+            "27082440"
+        );
+    }
+
+    #[test]
+    fn test_offline_cache() {
+        let number: RegistrationNumber = "27082440"
+            .parse()
+            .unwrap();
+        let cache = Arc::new(MemoryCache::new());
+
+        cache.set(
+            &number,
+            CacheEntry {
+                stored_at: SystemTime::UNIX_EPOCH,
+                subject: serde_json::from_str(SUBJECT).unwrap(),
+            },
+        );
+
+        let config = AresConfig {
+            base_url: serve(
+                vec![
+                    (
+                        503, "",
+                    ),
+                ],
+            ),
+            max_retries: 0,
+            cache: Some(cache.clone()),
+            ..Default::default()
+        };
+
+        assert!(
+            matches!(
+                AresClient::new(config.clone())
+                    .unwrap()
+                    .fetch_subject(&number),
+                Err(Error::ServerError(503))
+            )
+        );
+
+        let config = AresConfig {
+            base_url: serve(
+                vec![
+                    (
+                        503, "",
+                    ),
+                ],
+            ),
+            offline: true,
+            ..config
+        };
+
+        assert_eq!(
+            AresClient::new(config)
+                .unwrap()
+                .fetch_subject(&number)
+                .unwrap()
+                .name,
+            "Alza.cz a.s."
+        );
+    }
+
+    #[test]
+    fn test_fetch_many() {
+        let client = AresClient::new(
+            AresConfig {
+                base_url: serve(
+                    vec![
+                        (
+                            200, SUBJECT,
+                        ),
+                        (
+                            404, "{}",
+                        ),
+                    ],
+                ),
+                concurrency: 1,
+                request_interval: Duration::from_millis(1),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let found: RegistrationNumber = "27082440"
+            .parse()
+            .unwrap();
+        let missing: RegistrationNumber = "25596641"
+            .parse()
+            .unwrap();
+
+        let results = client.fetch_many(
+            [
+                found.clone(),
+                missing.clone(),
+            ],
+        );
+
+        assert_eq!(
+            results.len(),
+            2
+        );
+        assert!(results[&found].is_ok());
+        assert!(
+            matches!(
+                results[&missing],
+                Err(Error::NotFound(_))
+            )
+        );
+    }
+
+    #[test]
+    fn test_fetch_many_cached() {
+        let found: RegistrationNumber = "27082440"
+            .parse()
+            .unwrap();
+        let cached: RegistrationNumber = "25596641"
+            .parse()
+            .unwrap();
+        let cache = Arc::new(MemoryCache::new());
+
+        cache.set(
+            &cached,
+            CacheEntry::new(serde_json::from_str(SUBJECT).unwrap()),
+        );
+
+        // A single response, a repeated request would fail
+        let client = AresClient::new(
+            AresConfig {
+                base_url: serve(
+                    vec![
+                        (
+                            200, SUBJECT,
+                        ),
+                    ],
+                ),
+                cache: Some(cache),
+                concurrency: 1,
+                request_interval: Duration::from_secs(60),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let start = std::time::Instant::now();
+        let results = client.fetch_many(
+            [
+                cached.clone(),
+                found.clone(),
+                found.clone(),
+                cached.clone(),
+            ],
+        );
+
+        // The cached subject doesn't take the first slot of the limiter
+        assert!(start.elapsed() < Duration::from_secs(30));
+        assert_eq!(
+            results.len(),
+            2
+        );
+        assert!(results[&found].is_ok());
+        assert!(results[&cached].is_ok());
+    }
+
+    #[test]
+    fn test_unsupported_country() {
+        let client = client(serve(vec![]));
+
+        assert!(
+            matches!(
+                client.fetch_subject(
+                    &"SK:31333532"
+                        .parse()
+                        .unwrap()
+                ),
+                Err(Error::UnsupportedCountry(_))
+            )
+        );
+    }
+
+    #[test]
+    fn test_not_found() {
+        let client = client(
+            serve(
+                vec![
+                    (
+                        404, "{}",
+                    ),
+                ],
+            ),
+        );
+
+        assert!(
+            matches!(
+                client.fetch(
+                    "25596641"
+                        .parse()
+                        .unwrap()
+                ),
+                Err(Error::NotFound(_))
+            )
+        );
+    }
+
+    #[test]
+    fn test_rate_limited() {
+        let client = client(serve(vec![(429, ""); 3]));
+
+        assert!(
+            matches!(
+                client.fetch(
+                    "25596641"
+                        .parse()
+                        .unwrap()
+                ),
+                Err(Error::RateLimited)
+            )
+        );
+    }
+}
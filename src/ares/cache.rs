@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::AresSubject;
+use crate::registration_number::RegistrationNumber;
+
+/// Cached ARES subject
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CacheEntry {
+    pub stored_at: SystemTime,
+    pub subject: AresSubject,
+}
+
+impl CacheEntry {
+    pub fn new(subject: AresSubject) -> Self {
+        Self {
+            stored_at: SystemTime::now(),
+            subject,
+        }
+    }
+
+    /// Returns whether the entry is younger than `ttl`.
+    pub fn is_fresh(
+        &self,
+        ttl: Duration,
+    ) -> bool {
+        self.stored_at
+            .elapsed()
+            .map(|age| age < ttl)
+            .unwrap_or(false)
+    }
+}
+
+/// Storage of the subjects fetched from ARES, keyed by IČO.
+///
+/// The cache is best effort, failing to store an entry must not fail the lookup.
+pub trait AresCache: Debug + Send + Sync {
+    fn get(
+        &self,
+        number: &RegistrationNumber,
+    ) -> Option<CacheEntry>;
+
+    fn set(
+        &self,
+        number: &RegistrationNumber,
+        entry: CacheEntry,
+    );
+}
+
+/// Cache kept in memory for the lifetime of the process
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+impl MemoryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl AresCache for MemoryCache {
+    fn get(
+        &self,
+        number: &RegistrationNumber,
+    ) -> Option<CacheEntry> {
+        self.entries
+            .lock()
+            .ok()?
+            .get(number.get())
+            .cloned()
+    }
+
+    fn set(
+        &self,
+        number: &RegistrationNumber,
+        entry: CacheEntry,
+    ) {
+        if let Ok(mut entries) = self
+            .entries
+            .lock()
+        {
+            entries.insert(
+                number
+                    .get()
+                    .to_string(),
+                entry,
+            );
+        }
+    }
+}
+
+/// Cache storing every subject as a JSON file in a directory
+#[derive(Debug, Clone)]
+pub struct FileCache {
+    directory: PathBuf,
+}
+
+impl FileCache {
+    /// Creates the cache, the directory is created with the first stored entry.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    fn path(
+        &self,
+        number: &RegistrationNumber,
+    ) -> PathBuf {
+        self.directory
+            .join(
+                format!(
+                    "{}.json",
+                    number.get()
+                ),
+            )
+    }
+}
+
+impl AresCache for FileCache {
+    fn get(
+        &self,
+        number: &RegistrationNumber,
+    ) -> Option<CacheEntry> {
+        let content = fs::read_to_string(self.path(number)).ok()?;
+
+        serde_json::from_str(&content).ok()
+    }
+
+    fn set(
+        &self,
+        number: &RegistrationNumber,
+        entry: CacheEntry,
+    ) {
+        let Ok(content) = serde_json::to_string(&entry) else {
+            return;
+        };
+
+        if fs::create_dir_all(&self.directory).is_ok() {
+            let _ = fs::write(
+                self.path(number),
+                content,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ares::tests::SUBJECT;
+
+    #[test]
+    fn test_file_cache() {
+        let directory = std::env::temp_dir().join(
+            format!(
+                "invoicero-ares-cache-{}",
+                std::process::id()
+            ),
+        );
+        let cache = FileCache::new(&directory);
+        let number: RegistrationNumber = "27082440"
+            .parse()
+            .unwrap();
+
+        assert!(
+            cache
+                .get(&number)
+                .is_none()
+        );
+
+        cache.set(
+            &number,
+            CacheEntry::new(serde_json::from_str(SUBJECT).unwrap()),
+        );
+
+        let entry = cache
+            .get(&number)
+            .unwrap();
+
+        assert_eq!(
+            entry
+                .subject
+                .name,
+            "Alza.cz a.s."
+        );
+        assert!(entry.is_fresh(Duration::from_secs(60)));
+        assert!(!entry.is_fresh(Duration::ZERO));
+
+        let _ = fs::remove_dir_all(directory);
+    }
+}
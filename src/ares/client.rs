@@ -0,0 +1,291 @@
+use super::AresConfig;
+use super::AresSubject;
+use super::CacheEntry;
+use super::Error;
+use crate::address::Address;
+use crate::country::CountryCode;
+use crate::entity::eu::Entity;
+use crate::http;
+use crate::http::Retry;
+use crate::registration_number::RegistrationNumber;
+
+impl AresConfig {
+    pub(super) fn url(
+        &self,
+        path: &str,
+    ) -> String {
+        http::url(
+            &self.base_url,
+            path,
+        )
+    }
+
+    pub(super) fn retry(&self) -> Retry {
+        Retry {
+            max_retries: self.max_retries,
+            backoff: self.backoff,
+            max_retry_after: self.max_retry_after,
+        }
+    }
+}
+
+/// ARES only knows Czech subjects, other numbers are refused before anything is sent
+pub(super) fn validate(number: &RegistrationNumber) -> Result<(), Error> {
+    match number.country() {
+        CountryCode::Cz => Ok(()),
+        country => {
+            Err(
+                Error::UnsupportedCountry(
+                    format!(
+                        "{}:{}",
+                        country, number
+                    ),
+                ),
+            )
+        }
+    }
+}
+
+impl AresConfig {
+    /// Looks up the subject in the cache, the entry may be expired
+    pub(super) fn cached(
+        &self,
+        number: &RegistrationNumber,
+    ) -> Option<CacheEntry> {
+        self.cache
+            .as_ref()?
+            .get(number)
+    }
+
+    /// Stores the fetched subject in the cache.
+    ///
+    /// When ARES is unavailable in offline mode the expired entry is used instead.
+    pub(super) fn settle(
+        &self,
+        number: &RegistrationNumber,
+        body: Result<String, Error>,
+        cached: Option<CacheEntry>,
+    ) -> Result<AresSubject, Error> {
+        match body.and_then(|body| parse_subject(&body)) {
+            Ok(subject) => {
+                if let Some(cache) = &self.cache {
+                    cache.set(
+                        number,
+                        CacheEntry::new(subject.clone()),
+                    );
+                }
+
+                Ok(subject)
+            }
+            Err(Error::TransportError(_) | Error::ServerError(_) | Error::RateLimited)
+                if self.offline && cached.is_some() =>
+            {
+                Ok(
+                    cached
+                        .unwrap()
+                        .subject,
+                )
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+pub(super) fn parse_subject(body: &str) -> Result<AresSubject, Error> {
+    serde_json::from_str(body).map_err(Error::JsonError)
+}
+
+/// DIČ which doesn't pass the validation is left out, an unknown country of the seat is an error
+pub(crate) fn to_entity(
+    number: RegistrationNumber,
+    ares_response: AresSubject,
+) -> Result<Entity, Error> {
+    let office = &ares_response.office;
+    let orientation_number = office
+        .orientation_number
+        .map(
+            |x| {
+                format!(
+                    "{}{}",
+                    x,
+                    office
+                        .orientation_letter
+                        .as_deref()
+                        .unwrap_or_default()
+                )
+            },
+        );
+    let house_number = match (
+        office.house_number,
+        orientation_number,
+    ) {
+        (Some(house_number), Some(orientation_number)) => {
+            format!(
+                "{}/{}",
+                house_number, orientation_number
+            )
+        }
+        (Some(house_number), None) => house_number.to_string(),
+        (None, Some(orientation_number)) => orientation_number,
+        (None, None) => {
+            office
+                .address_number
+                .clone()
+                .unwrap_or_default()
+        }
+    };
+
+    let country = office
+        .country_code
+        .parse()
+        .map_err(
+            |_| {
+                Error::UnknownCountry(
+                    office
+                        .country_code
+                        .clone(),
+                )
+            },
+        )?;
+
+    Ok(
+        Entity::new(
+            number,
+            ares_response.name,
+            Address::new(
+                country,
+                ares_response
+                    .office
+                    .city_part
+                    .map(
+                        |x| {
+                            x.split('-')
+                                .collect::<Vec<&str>>()
+                                .join(" - ")
+                        },
+                    )
+                    .or(
+                        ares_response
+                            .office
+                            .municipality_name,
+                    )
+                    .unwrap_or_default(),
+                ares_response
+                    .office
+                    .street
+                    .or(
+                        ares_response
+                            .office
+                            .municipality_part_name,
+                    )
+                    .unwrap_or_default(),
+                ares_response
+                    .office
+                    .postal_code
+                    .map(|x| x.to_string())
+                    .or(
+                        ares_response
+                            .office
+                            .postal_code_text,
+                    )
+                    .unwrap_or_default(),
+                house_number,
+            ),
+            ares_response
+                .dic
+                .and_then(
+                    |dic| {
+                        dic.parse()
+                            .ok()
+                    },
+                ),
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ares::tests::FOREIGN_SUBJECT;
+    use crate::ares::tests::SUBJECT;
+    use crate::country::CountryCode;
+
+    #[test]
+    fn test_foreign_seat() {
+        let number: RegistrationNumber = "07513364"
+            .parse()
+            .unwrap();
+
+        let subject = parse_subject(FOREIGN_SUBJECT).unwrap();
+
+        assert!(
+            subject
+                .office
+                .municipality_code
+                .is_none()
+        );
+
+        let entity = to_entity(
+            number, subject,
+        )
+        .unwrap();
+
+        assert_eq!(
+            entity.address,
+            Address::new(
+                CountryCode::De,
+                "Berlin",
+                "Valeska-Gert-Straße",
+                "10243",
+                "1",
+            )
+        );
+    }
+
+    #[test]
+    fn test_country() {
+        let number: RegistrationNumber = "27082440"
+            .parse()
+            .unwrap();
+
+        for (code, country) in [
+            (
+                "SK",
+                Some(CountryCode::Sk),
+            ),
+            (
+                "US",
+                Some(CountryCode::Us),
+            ),
+            (
+                "XX", None,
+            ),
+        ] {
+            let subject = parse_subject(
+                &SUBJECT.replace(
+                    r#""kodStatu": "CZ""#,
+                    &format!(
+                        r#""kodStatu": "{}""#,
+                        code
+                    ),
+                ),
+            )
+            .unwrap();
+
+            assert_eq!(
+                to_entity(
+                    number.clone(),
+                    subject
+                )
+                .ok()
+                .map(
+                    |x| {
+                        x.address
+                            .country
+                    }
+                ),
+                country
+            );
+        }
+    }
+}
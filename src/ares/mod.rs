@@ -0,0 +1,393 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde::Serialize;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod cache;
+#[cfg(
+    any(
+        feature = "blocking",
+        feature = "async"
+    )
+)]
+mod client;
+#[cfg(feature = "async")]
+pub mod nonblocking;
+pub mod search;
+
+#[cfg(feature = "blocking")]
+pub use blocking::fetch_from_ares;
+#[cfg(feature = "blocking")]
+pub use blocking::AresClient;
+pub use cache::AresCache;
+pub use cache::CacheEntry;
+pub use cache::FileCache;
+pub use cache::MemoryCache;
+#[cfg(
+    any(
+        feature = "blocking",
+        feature = "async"
+    )
+)]
+pub(crate) use client::to_entity;
+#[cfg(feature = "async")]
+pub use nonblocking::fetch_from_ares_async;
+#[cfg(feature = "async")]
+pub use nonblocking::AsyncAresClient;
+pub use search::AresSearch;
+pub use search::AresSearchResult;
+pub use search::AresSubjectSummary;
+
+/// Status of the subject in one of the source registers
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RegistrationStatus {
+    #[serde(rename = "AKTIVNI")]
+    Active,
+
+    #[serde(rename = "NEAKTIVNI")]
+    Inactive,
+
+    #[serde(rename = "ZANIKLY")]
+    Dissolved,
+
+    #[default]
+    #[serde(
+        rename = "NEEXISTUJICI",
+        other
+    )]
+    NonExistent,
+}
+
+/// Statuses of the subject in the registers ARES collects data from
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct AresListOfRegistrations {
+    /// Veřejný rejstřík (commercial register)
+    #[serde(rename = "stavZdrojeVr")]
+    pub vr: RegistrationStatus,
+
+    /// Registr ekonomických subjektů
+    #[serde(rename = "stavZdrojeRes")]
+    pub res: RegistrationStatus,
+
+    /// Registr živnostenského podnikání (trade register)
+    #[serde(rename = "stavZdrojeRzp")]
+    pub rzp: RegistrationStatus,
+
+    /// Národní registr poskytovatelů zdravotních služeb
+    #[serde(rename = "stavZdrojeNrpzs")]
+    pub nrpzs: RegistrationStatus,
+
+    /// Registr politických stran a hnutí
+    #[serde(rename = "stavZdrojeRpsh")]
+    pub rpsh: RegistrationStatus,
+
+    /// Registr církví a náboženských společností
+    #[serde(rename = "stavZdrojeRcns")]
+    pub rcns: RegistrationStatus,
+
+    /// Registr zdravotních pojišťoven
+    #[serde(rename = "stavZdrojeSzr")]
+    pub szr: RegistrationStatus,
+
+    /// Registr plátců DPH (VAT register)
+    #[serde(rename = "stavZdrojeDph")]
+    pub dph: RegistrationStatus,
+
+    /// Registr společenství vlastníků jednotek
+    #[serde(rename = "stavZdrojeSd")]
+    pub sd: RegistrationStatus,
+
+    /// Insolvenční rejstřík
+    #[serde(rename = "stavZdrojeIr")]
+    pub ir: RegistrationStatus,
+
+    /// Centrální evidence úpadců
+    #[serde(rename = "stavZdrojeCeu")]
+    pub ceu: RegistrationStatus,
+
+    /// Registr škol
+    #[serde(rename = "stavZdrojeRs")]
+    pub rs: RegistrationStatus,
+
+    /// Registr ekonomických subjektů - daňoví poplatníci
+    #[serde(rename = "stavZdrojeRed")]
+    pub red: RegistrationStatus,
+}
+
+/// Registered office of the subject.
+///
+/// The codes of the Czech address register are missing when the seat is abroad,
+/// such addresses usually give only the municipality, the street and the postal code as text.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AresSidlo {
+    #[serde(rename = "kodStatu")]
+    pub country_code: String,
+
+    #[serde(rename = "nazevStatu")]
+    pub country_name: Option<String>,
+
+    #[serde(rename = "kodKraje")]
+    pub region_code: Option<u32>,
+
+    #[serde(rename = "nazevKraje")]
+    pub region_name: Option<String>,
+
+    #[serde(rename = "kodOkresu")]
+    pub district_code: Option<u32>,
+
+    #[serde(rename = "nazevOkresu")]
+    pub district_name: Option<String>,
+
+    #[serde(rename = "kodObce")]
+    pub municipality_code: Option<u32>,
+
+    #[serde(rename = "nazevObce")]
+    pub municipality_name: Option<String>,
+
+    #[serde(rename = "cisloDomovni")]
+    pub house_number: Option<u32>,
+
+    /// House number of a foreign address, it may contain letters
+    #[serde(rename = "cisloDoAdresy")]
+    pub address_number: Option<String>,
+
+    #[serde(rename = "kodCastiObce")]
+    pub municipality_part: Option<u32>,
+
+    #[serde(rename = "nazevCastiObce")]
+    pub municipality_part_name: Option<String>,
+
+    #[serde(rename = "kodAdresnihoMista")]
+    pub address_place_code: Option<u32>,
+
+    #[serde(rename = "psc")]
+    pub postal_code: Option<u32>,
+
+    /// Postal code of a foreign address
+    #[serde(rename = "pscTxt")]
+    pub postal_code_text: Option<String>,
+
+    #[serde(rename = "textovaAdresa")]
+    pub text_address: Option<String>,
+
+    #[serde(rename = "typCisloDomovni")]
+    pub house_number_type: Option<u32>,
+
+    #[serde(rename = "standardizaceAdresy")]
+    pub address_normalized: Option<bool>,
+
+    #[serde(rename = "cisloOrientacni")]
+    pub orientation_number: Option<u32>,
+
+    #[serde(rename = "cisloOrientacniPismeno")]
+    pub orientation_letter: Option<String>,
+
+    #[serde(rename = "nazevUlice")]
+    pub street: Option<String>,
+
+    #[serde(rename = "nazevMestskeCastiObvodu")]
+    pub city_part: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AresAdresa {
+    #[serde(rename = "radekAdresy1")]
+    pub first_line: String,
+
+    #[serde(rename = "radekAdresy2")]
+    pub second_line: Option<String>,
+}
+
+/// Subject as returned by ARES
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AresSubject {
+    pub ico: String,
+
+    #[serde(rename = "obchodniJmeno")]
+    pub name: String,
+
+    #[serde(rename = "sidlo")]
+    pub office: AresSidlo,
+
+    /// Code of the legal form from the ČSÚ classification, e.g. 112 for s.r.o.
+    #[serde(rename = "pravniForma")]
+    pub legal_form: Option<String>,
+
+    /// Code of the tax office
+    #[serde(rename = "financniUrad")]
+    pub tax_office: Option<String>,
+
+    #[serde(rename = "datumVzniku")]
+    pub created_at: Option<NaiveDate>,
+
+    #[serde(rename = "datumZaniku")]
+    pub dissolved_at: Option<NaiveDate>,
+
+    #[serde(rename = "datumAktualizace")]
+    pub updated_at: Option<NaiveDate>,
+
+    #[serde(rename = "icoId")]
+    pub registration_number_id: Option<String>,
+
+    #[serde(rename = "adresaDorucovaci")]
+    pub address: AresAdresa,
+
+    #[serde(
+        rename = "seznamRegistraci",
+        default
+    )]
+    pub list_of_registrations: AresListOfRegistrations,
+
+    /// Economic activities (CZ-NACE codes)
+    #[serde(
+        rename = "czNace",
+        default
+    )]
+    pub nace: Vec<String>,
+
+    pub dic: Option<String>,
+}
+
+impl AresSubject {
+    /// Returns whether the subject still exists.
+    pub fn is_active(&self) -> bool {
+        self.dissolved_at
+            .is_none()
+    }
+
+    /// Returns whether the subject is active in the VAT register.
+    pub fn is_vat_payer(&self) -> bool {
+        self.list_of_registrations
+            .dph
+            == RegistrationStatus::Active
+    }
+}
+
+/// Base URL of the public ARES REST API
+pub const DEFAULT_BASE_URL: &str = "https://ares.gov.cz/ekonomicke-subjekty-v-be/rest";
+
+#[cfg(
+    any(
+        feature = "blocking",
+        feature = "async"
+    )
+)]
+pub use crate::http::Error;
+
+/// Configuration of the ARES client
+#[derive(Debug, Clone)]
+pub struct AresConfig {
+    pub base_url: String,
+    /// Timeout of the whole request
+    pub timeout: Duration,
+    pub connect_timeout: Duration,
+    /// How many times a request is retried after a 429 or 5xx response
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled with every next one
+    pub backoff: Duration,
+    /// Longest `Retry-After` the client waits for, a longer one fails with `Error::RateLimited`
+    pub max_retry_after: Duration,
+    /// Cache of the fetched subjects, `None` disables caching
+    pub cache: Option<Arc<dyn AresCache>>,
+    /// How long a cached subject is used without asking ARES again
+    pub cache_ttl: Duration,
+    /// Serve expired cache entries when ARES can't be reached
+    pub offline: bool,
+    /// Number of concurrent requests of a bulk lookup
+    pub concurrency: usize,
+    /// Minimal delay between two requests of a bulk lookup
+    pub request_interval: Duration,
+}
+
+impl Default for AresConfig {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            timeout: Duration::from_secs(10),
+            connect_timeout: Duration::from_secs(5),
+            max_retries: 3,
+            backoff: Duration::from_millis(500),
+            max_retry_after: Duration::from_secs(60),
+            cache: None,
+            cache_ttl: Duration::from_secs(24 * 60 * 60),
+            offline: false,
+            concurrency: 4,
+            // ARES allows about 500 requests per minute
+            request_interval: Duration::from_millis(150),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    pub(crate) const SUBJECT: &str = r#"{
+        "ico": "27082440",
+        "obchodniJmeno": "Alza.cz a.s.",
+        "sidlo": {
+            "kodStatu": "CZ",
+            "nazevStatu": "Česká republika",
+            "kodKraje": 19,
+            "nazevKraje": "Hlavní město Praha",
+            "kodObce": 554782,
+            "nazevObce": "Praha",
+            "cisloDomovni": 1522,
+            "kodCastiObce": 490245,
+            "nazevCastiObce": "Holešovice",
+            "kodAdresnihoMista": 22314199,
+            "psc": 17000,
+            "textovaAdresa": "Jankovcova 1522/53, Holešovice, 17000 Praha 7",
+            "typCisloDomovni": 1,
+            "standardizaceAdresy": true,
+            "cisloOrientacni": 53,
+            "nazevUlice": "Jankovcova",
+            "nazevMestskeCastiObvodu": "Praha 7"
+        },
+        "adresaDorucovaci": {
+            "radekAdresy1": "Jankovcova 1522/53"
+        },
+        "pravniForma": "121",
+        "financniUrad": "007",
+        "datumVzniku": "2003-08-26",
+        "datumAktualizace": "2024-05-02",
+        "icoId": "27082440",
+        "seznamRegistraci": {
+            "stavZdrojeVr": "AKTIVNI",
+            "stavZdrojeRes": "AKTIVNI",
+            "stavZdrojeRzp": "AKTIVNI",
+            "stavZdrojeNrpzs": "NEEXISTUJICI",
+            "stavZdrojeDph": "AKTIVNI",
+            "stavZdrojeIr": "NEEXISTUJICI"
+        },
+        "czNace": ["47910", "46900"],
+        "dic": "CZ27082440"
+    }"#;
+
+    /// Branch of a foreign company, the seat is outside of the Czech address register
+    pub(crate) const FOREIGN_SUBJECT: &str = r#"{
+        "ico": "07513364",
+        "obchodniJmeno": "Zalando SE, odštěpný závod",
+        "sidlo": {
+            "kodStatu": "DE",
+            "nazevStatu": "Spolková republika Německo",
+            "nazevObce": "Berlin",
+            "nazevUlice": "Valeska-Gert-Straße",
+            "cisloDoAdresy": "1",
+            "pscTxt": "10243",
+            "textovaAdresa": "Valeska-Gert-Straße 1, 10243 Berlin, Spolková republika Německo"
+        },
+        "adresaDorucovaci": {
+            "radekAdresy1": "Valeska-Gert-Straße 1"
+        },
+        "pravniForma": "421",
+        "datumVzniku": "2018-10-01",
+        "seznamRegistraci": {
+            "stavZdrojeVr": "AKTIVNI",
+            "stavZdrojeRes": "AKTIVNI"
+        }
+    }"#;
+}
@@ -0,0 +1,319 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use reqwest::header::CONTENT_TYPE;
+use tokio::task::JoinSet;
+
+use super::client::to_entity;
+use super::client::validate;
+use super::AresConfig;
+use super::AresSearch;
+use super::AresSearchResult;
+use super::AresSubject;
+use super::Error;
+use crate::entity::eu::Entity;
+use crate::http::RateLimiter;
+use crate::registration_number::RegistrationNumber;
+
+/// Asynchronous client of the ARES registry, for use inside a tokio runtime
+#[derive(Debug, Clone)]
+pub struct AsyncAresClient {
+    config: AresConfig,
+    client: reqwest::Client,
+}
+
+impl AsyncAresClient {
+    pub fn new(config: AresConfig) -> Result<Self, Error> {
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout)
+            .build()
+            .map_err(Error::TransportError)?;
+
+        Ok(
+            Self {
+                config,
+                client,
+            },
+        )
+    }
+
+    /// Fetches the full record of the subject from ARES registry, or from the cache if configured.
+    ///
+    /// Non-Czech numbers are refused with `Error::UnsupportedCountry` before anything is sent.
+    pub async fn fetch_subject(
+        &self,
+        number: &RegistrationNumber,
+    ) -> Result<AresSubject, Error> {
+        self.fetch_subject_limited(
+            number, None,
+        )
+        .await
+    }
+
+    /// Fetches the subject, every request sent to ARES waits for the `limiter` first
+    async fn fetch_subject_limited(
+        &self,
+        number: &RegistrationNumber,
+        limiter: Option<&RateLimiter>,
+    ) -> Result<AresSubject, Error> {
+        validate(number)?;
+
+        let url = self
+            .config
+            .url(
+                &format!(
+                    "ekonomicke-subjekty/{}",
+                    number.get()
+                ),
+            );
+
+        let cached = self
+            .config
+            .cached(number);
+
+        if let Some(entry) = &cached {
+            if entry.is_fresh(
+                self.config
+                    .cache_ttl,
+            ) {
+                return Ok(
+                    entry
+                        .subject
+                        .clone(),
+                );
+            }
+        }
+
+        let body = self
+            .config
+            .retry()
+            .send_async(
+                || {
+                    self.client
+                        .get(&url)
+                },
+                Some(number),
+                limiter,
+            )
+            .await;
+
+        self.config
+            .settle(
+                number, body, cached,
+            )
+    }
+
+    /// Searches subjects by the business name, returns one page of the results
+    pub async fn search(
+        &self,
+        search: &AresSearch,
+    ) -> Result<AresSearchResult, Error> {
+        let url = self
+            .config
+            .url("ekonomicke-subjekty/vyhledat");
+        let request = serde_json::to_string(&search.request()).map_err(Error::JsonError)?;
+
+        let body = self
+            .config
+            .retry()
+            .send_async(
+                || {
+                    self.client
+                        .post(&url)
+                        .header(
+                            CONTENT_TYPE,
+                            "application/json",
+                        )
+                        .body(request.clone())
+                },
+                None,
+                None,
+            )
+            .await?;
+
+        serde_json::from_str(&body).map_err(Error::JsonError)
+    }
+
+    /// Fetches the subject from ARES registry and converts it into an entity
+    pub async fn fetch(
+        &self,
+        number: RegistrationNumber,
+    ) -> Result<Entity, Error> {
+        let subject = self
+            .fetch_subject(&number)
+            .await?;
+
+        to_entity(
+            number, subject,
+        )
+    }
+
+    /// Fetches many subjects at once with bounded concurrency.
+    ///
+    /// Every number gets its own result, so a single failed lookup doesn't abort the batch.
+    /// Repeated numbers are fetched once, cached subjects don't wait for the rate limit.
+    pub async fn fetch_many(
+        &self,
+        numbers: impl IntoIterator<Item = RegistrationNumber>,
+    ) -> HashMap<RegistrationNumber, Result<Entity, Error>> {
+        let mut seen = HashSet::new();
+        let queue = Arc::new(
+            Mutex::new(
+                numbers
+                    .into_iter()
+                    .filter(|x| seen.insert(x.clone()))
+                    .collect::<VecDeque<_>>(),
+            ),
+        );
+        let limiter = Arc::new(
+            RateLimiter::new(
+                self.config
+                    .request_interval,
+            ),
+        );
+
+        let mut tasks = JoinSet::new();
+
+        for _ in 0..self
+            .config
+            .concurrency
+            .max(1)
+        {
+            let client = self.clone();
+            let queue = queue.clone();
+            let limiter = limiter.clone();
+
+            tasks.spawn(
+                async move {
+                    let mut results = Vec::new();
+
+                    loop {
+                        let next = queue
+                            .lock()
+                            .unwrap_or_else(|e| e.into_inner())
+                            .pop_front();
+
+                        let Some(number) = next else {
+                            break;
+                        };
+
+                        let result = client
+                            .fetch_subject_limited(
+                                &number,
+                                Some(&limiter),
+                            )
+                            .await
+                            .and_then(
+                                |subject| {
+                                    to_entity(
+                                        number.clone(),
+                                        subject,
+                                    )
+                                },
+                            );
+
+                        results.push(
+                            (
+                                number, result,
+                            ),
+                        );
+                    }
+
+                    results
+                },
+            );
+        }
+
+        let mut results = HashMap::new();
+
+        while let Some(task) = tasks
+            .join_next()
+            .await
+        {
+            if let Ok(task_results) = task {
+                results.extend(task_results);
+            }
+        }
+
+        results
+    }
+}
+
+/// Fetches data from ARES registry using the default configuration without blocking
+pub async fn fetch_from_ares_async(number: RegistrationNumber) -> Result<Entity, Error> {
+    AsyncAresClient::new(AresConfig::default())?
+        .fetch(number)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::ares::tests::SUBJECT;
+    use crate::http::tests::serve;
+
+    #[tokio::test]
+    async fn test_fetch() {
+        let client = AsyncAresClient::new(
+            AresConfig {
+                base_url: serve(
+                    vec![
+                        (
+                            429, "",
+                        ),
+                        (
+                            200, SUBJECT,
+                        ),
+                    ],
+                ),
+                backoff: Duration::from_millis(1),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let entity = client
+            .fetch(
+                "27082440"
+                    .parse()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            entity.name,
+            "Alza.cz a.s."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_country() {
+        let client = AsyncAresClient::new(
+            AresConfig {
+                base_url: serve(vec![]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert!(
+            matches!(
+                client
+                    .fetch_subject(
+                        &"SK:31333532"
+                            .parse()
+                            .unwrap()
+                    )
+                    .await,
+                Err(Error::UnsupportedCountry(_))
+            )
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::registration_number::RegistrationNumber;
+use crate::registration_number::RegistrationNumberError;
+
+/// Search of subjects by the business name, one page of the results
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AresSearch {
+    pub name: String,
+    /// Restricts the search to subjects with the office in the municipality
+    pub municipality: Option<String>,
+    /// Index of the first returned result
+    pub start: u32,
+    /// Number of results on the page
+    pub count: u32,
+}
+
+impl AresSearch {
+    pub fn new(
+        name: impl Into<String>,
+        municipality: Option<impl Into<String>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            municipality: municipality.map(|x| x.into()),
+            start: 0,
+            count: 20,
+        }
+    }
+
+    /// Returns the search of the following page.
+    pub fn next_page(&self) -> Self {
+        Self {
+            start: self.start + self.count,
+            ..self.clone()
+        }
+    }
+
+    #[cfg(
+        any(
+            feature = "blocking",
+            feature = "async"
+        )
+    )]
+    pub(super) fn request(&self) -> AresSearchRequest<'_> {
+        AresSearchRequest {
+            start: self.start,
+            count: self.count,
+            name: &self.name,
+            office: self
+                .municipality
+                .as_deref()
+                .map(
+                    |text_address| {
+                        AresSearchAddress {
+                            text_address,
+                        }
+                    },
+                ),
+        }
+    }
+}
+
+/// Body of the `ekonomicke-subjekty/vyhledat` request
+#[cfg(
+    any(
+        feature = "blocking",
+        feature = "async"
+    )
+)]
+#[derive(Debug, Serialize)]
+pub(super) struct AresSearchRequest<'a> {
+    start: u32,
+
+    #[serde(rename = "pocet")]
+    count: u32,
+
+    #[serde(rename = "obchodniJmeno")]
+    name: &'a str,
+
+    #[serde(
+        rename = "sidlo",
+        skip_serializing_if = "Option::is_none"
+    )]
+    office: Option<AresSearchAddress<'a>>,
+}
+
+#[cfg(
+    any(
+        feature = "blocking",
+        feature = "async"
+    )
+)]
+#[derive(Debug, Serialize)]
+struct AresSearchAddress<'a> {
+    #[serde(rename = "textovaAdresa")]
+    text_address: &'a str,
+}
+
+/// One page of the search results
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AresSearchResult {
+    /// Number of all subjects matching the search
+    #[serde(rename = "pocetCelkem")]
+    pub total: u32,
+
+    #[serde(
+        rename = "ekonomickeSubjekty",
+        default
+    )]
+    pub subjects: Vec<AresSubjectSummary>,
+}
+
+/// Basic information about a found subject
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AresSubjectSummary {
+    pub ico: String,
+
+    #[serde(rename = "obchodniJmeno")]
+    pub name: String,
+
+    #[serde(rename = "sidlo")]
+    pub office: Option<AresOfficeSummary>,
+
+    pub dic: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AresOfficeSummary {
+    #[serde(rename = "nazevObce")]
+    pub municipality_name: Option<String>,
+
+    #[serde(rename = "textovaAdresa")]
+    pub text_address: Option<String>,
+}
+
+impl AresSubjectSummary {
+    /// Returns the registration number, which can be used to fetch the whole entity.
+    pub fn registration_number(&self) -> Result<RegistrationNumber, RegistrationNumberError> {
+        self.ico
+            .parse()
+    }
+}
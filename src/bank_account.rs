@@ -0,0 +1,416 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use iban::Iban;
+use iban::IbanLike;
+use iso_currency::Currency;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::country::CountryCode;
+use crate::registration_number::all_digits;
+use crate::registration_number::weighted_sum;
+
+const PREFIX_WEIGHTS: [u32; 6] = [
+    10, 5, 8, 4, 2, 1,
+];
+const NUMBER_WEIGHTS: [u32; 10] = [
+    6, 3, 7, 9, 10, 5, 8, 4, 2, 1,
+];
+
+/// Domestic bank account number used in the Czech Republic and Slovakia,
+/// written as `prefix-number/bank code`, e.g. `19-2000145399/0800`.
+///
+/// The prefix (předčíslí) is optional. Both countries share the format and the check digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CzBankAccount {
+    country: CountryCode,
+    prefix: String,
+    number: String,
+    bank_code: String,
+}
+
+/// Account of the contractor used to receive payments in the currency
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct BankAccount {
+    pub currency: Currency,
+    pub iban: Iban,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bic: Option<String>,
+    /// Name of the account for the user, not printed on invoices
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl BankAccount {
+    pub fn new(
+        currency: Currency,
+        iban: Iban,
+    ) -> Self {
+        Self {
+            currency,
+            iban,
+            bic: None,
+            label: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BankAccountError {
+    /// The account isn't written as `prefix-number/bank code`
+    InvalidFormat,
+    /// The prefix has more than 6 digits or fails the check
+    InvalidPrefix,
+    /// The number has more than 10 digits or fails the check
+    InvalidNumber,
+    /// The bank code doesn't have 4 digits
+    InvalidBankCode,
+    /// Only Czech and Slovak accounts use the domestic format
+    UnsupportedCountry(String),
+}
+
+impl Display for BankAccountError {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            BankAccountError::InvalidFormat => {
+                write!(
+                    f,
+                    "Číslo účtu musí být ve tvaru předčíslí-číslo/kód banky"
+                )
+            }
+            BankAccountError::InvalidPrefix => {
+                write!(
+                    f,
+                    "Neplatné předčíslí účtu"
+                )
+            }
+            BankAccountError::InvalidNumber => {
+                write!(
+                    f,
+                    "Neplatné číslo účtu"
+                )
+            }
+            BankAccountError::InvalidBankCode => {
+                write!(
+                    f,
+                    "Neplatný kód banky"
+                )
+            }
+            BankAccountError::UnsupportedCountry(country) => {
+                write!(
+                    f,
+                    "Účet ze státu {} nelze zapsat v tuzemském formátu",
+                    country
+                )
+            }
+        }
+    }
+}
+
+impl Error for BankAccountError {}
+
+/// Checks the digits padded with zeros to the length of the weights, the weighted sum must be divisible by 11
+fn valid(
+    digits: &str,
+    weights: &[u32],
+) -> bool {
+    let padded = format!(
+        "{:0>width$}",
+        digits,
+        width = weights.len()
+    );
+
+    all_digits(digits)
+        && digits.len() <= weights.len()
+        && weighted_sum(
+            &padded, weights,
+        )
+        .is_some_and(|x| x % 11 == 0)
+}
+
+impl CzBankAccount {
+    /// Parses the account written as `prefix-number/bank code`, the prefix may be left out.
+    pub fn new(
+        country: CountryCode,
+        account: &str,
+    ) -> Result<Self, BankAccountError> {
+        if !matches!(
+            country,
+            CountryCode::Cz | CountryCode::Sk
+        ) {
+            return Err(BankAccountError::UnsupportedCountry(country.to_string()));
+        }
+
+        let account: String = account
+            .chars()
+            .filter(|x| !x.is_whitespace())
+            .collect();
+
+        let (account, bank_code) = account
+            .split_once('/')
+            .ok_or(BankAccountError::InvalidFormat)?;
+
+        let (prefix, number) = account
+            .split_once('-')
+            .unwrap_or(
+                (
+                    "", account,
+                ),
+            );
+
+        if !prefix.is_empty()
+            && !valid(
+                prefix,
+                &PREFIX_WEIGHTS,
+            )
+        {
+            return Err(BankAccountError::InvalidPrefix);
+        }
+
+        let number = number.trim_start_matches('0');
+
+        if number.len() < 2
+            || !valid(
+                number,
+                &NUMBER_WEIGHTS,
+            )
+        {
+            return Err(BankAccountError::InvalidNumber);
+        }
+
+        if bank_code.len() != 4 || !all_digits(bank_code) {
+            return Err(BankAccountError::InvalidBankCode);
+        }
+
+        Ok(
+            Self {
+                country,
+                prefix: prefix
+                    .trim_start_matches('0')
+                    .to_string(),
+                number: number.to_string(),
+                bank_code: bank_code.to_string(),
+            },
+        )
+    }
+
+    pub fn country(&self) -> CountryCode {
+        self.country
+    }
+
+    /// Returns the prefix (předčíslí) without the leading zeros, empty if the account has none.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Returns the number without the leading zeros.
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+
+    pub fn bank_code(&self) -> &str {
+        &self.bank_code
+    }
+
+    /// Returns the account without the bank code, e.g. `19-2000145399`.
+    pub fn account_number(&self) -> String {
+        match self
+            .prefix
+            .is_empty()
+        {
+            true => {
+                self.number
+                    .clone()
+            }
+            false => {
+                format!(
+                    "{}-{}",
+                    self.prefix, self.number
+                )
+            }
+        }
+    }
+
+    /// Converts the account into an IBAN, the check digits are computed by ISO 7064 MOD 97-10.
+    pub fn to_iban(&self) -> Iban {
+        let bban = format!(
+            "{}{:0>6}{:0>10}",
+            self.bank_code, self.prefix, self.number
+        );
+
+        let remainder = format!(
+            "{}{}00",
+            bban,
+            self.country
+                .code()
+        )
+        .chars()
+        .filter_map(|x| x.to_digit(36))
+        .fold(
+            0,
+            |remainder, digit| {
+                match digit {
+                    0..=9 => (remainder * 10 + digit) % 97,
+                    _ => (remainder * 100 + digit) % 97,
+                }
+            },
+        );
+
+        format!(
+            "{}{:02}{}",
+            self.country
+                .code(),
+            98 - remainder,
+            bban
+        )
+        .parse()
+        .expect("the BBAN of a CZ or SK account is always valid")
+    }
+}
+
+impl Display for CzBankAccount {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(
+            f,
+            "{}/{}",
+            self.account_number(),
+            self.bank_code
+        )
+    }
+}
+
+/// Parses a Czech account, Slovak accounts are created with `CzBankAccount::new`.
+impl FromStr for CzBankAccount {
+    type Err = BankAccountError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::new(
+            CountryCode::Cz,
+            value,
+        )
+    }
+}
+
+impl TryFrom<&Iban> for CzBankAccount {
+    type Error = BankAccountError;
+
+    fn try_from(iban: &Iban) -> Result<Self, Self::Error> {
+        let country = match iban.country_code() {
+            "CZ" => CountryCode::Cz,
+            "SK" => CountryCode::Sk,
+            country => return Err(BankAccountError::UnsupportedCountry(country.to_string())),
+        };
+
+        let bban = &iban.electronic_str()[4..];
+
+        Self::new(
+            country,
+            &format!(
+                "{}-{}/{}",
+                &bban[4..10],
+                &bban[10..],
+                &bban[..4]
+            ),
+        )
+    }
+}
+
+impl From<&CzBankAccount> for Iban {
+    fn from(account: &CzBankAccount) -> Self {
+        account.to_iban()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bank_account() {
+        let account: CzBankAccount = "19-2000145399/0800"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            account.prefix(),
+            "19"
+        );
+        assert_eq!(
+            account
+                .to_iban()
+                .electronic_str(),
+            "CZ6508000000192000145399"
+        );
+
+        let account: CzBankAccount = "0000001234567899 / 0800"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            account.to_string(),
+            "1234567899/0800"
+        );
+
+        for value in [
+            "19-2000145398/0800",
+            "18-2000145399/0800",
+            "2000145399/080",
+            "2000145399",
+            "0/0800",
+        ] {
+            assert!(
+                value
+                    .parse::<CzBankAccount>()
+                    .is_err(),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_iban_conversion() {
+        for value in [
+            "CZ6508000000192000145399",
+            "CZ5508000000001234567899",
+            "SK3112000000198742637541",
+        ] {
+            let iban: Iban = value
+                .parse()
+                .unwrap();
+            let account = CzBankAccount::try_from(&iban).unwrap();
+
+            assert_eq!(
+                Iban::from(&account),
+                iban
+            );
+        }
+
+        let account = CzBankAccount::new(
+            CountryCode::Sk,
+            "19-8742637541/1200",
+        )
+        .unwrap();
+
+        assert_eq!(
+            account
+                .to_iban()
+                .electronic_str(),
+            "SK3112000000198742637541"
+        );
+
+        let iban: Iban = "DE89370400440532013000"
+            .parse()
+            .unwrap();
+
+        assert!(CzBankAccount::try_from(&iban).is_err());
+    }
+}
@@ -0,0 +1,344 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::path::Path;
+use std::sync::LazyLock;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+use std::sync::RwLockReadGuard;
+
+use iban::Iban;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::bank_account::CzBankAccount;
+use crate::country::CountryCode;
+
+/// Code list of the Czech National Bank (ČNB)
+const CZ_BANK_CODES: &str = include_str!("../assets/bank_codes_cz.csv");
+/// Code list of the National Bank of Slovakia (NBS)
+const SK_BANK_CODES: &str = include_str!("../assets/bank_codes_sk.csv");
+
+static REGISTRY: LazyLock<RwLock<BankRegistry>> =
+    LazyLock::new(|| RwLock::new(BankRegistry::default()));
+
+/// Bank identified by its domestic code
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Bank {
+    pub code: String,
+    pub name: String,
+    /// SWIFT/BIC, not all banks have one
+    pub bic: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum BankRegistryError {
+    IoError(std::io::Error),
+    /// The line of the code list doesn't start with a 4-digit code, numbered from 1
+    InvalidLine(usize),
+}
+
+impl Display for BankRegistryError {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            BankRegistryError::IoError(e) => {
+                write!(
+                    f,
+                    "Číselník kódů bank nelze načíst: {}",
+                    e
+                )
+            }
+            BankRegistryError::InvalidLine(line) => {
+                write!(
+                    f,
+                    "Neplatný řádek {} číselníku kódů bank",
+                    line
+                )
+            }
+        }
+    }
+}
+
+impl Error for BankRegistryError {}
+
+impl From<std::io::Error> for BankRegistryError {
+    fn from(value: std::io::Error) -> Self {
+        BankRegistryError::IoError(value)
+    }
+}
+
+/// Parses the code list in the CSV format published by ČNB, `code;name;SWIFT` separated by semicolons.
+///
+/// The first line is the header.
+fn parse(list: &str) -> Result<BTreeMap<String, Bank>, BankRegistryError> {
+    list.trim_start_matches('\u{feff}')
+        .lines()
+        .enumerate()
+        .skip(1)
+        .filter(
+            |(_, line)| {
+                !line
+                    .trim()
+                    .is_empty()
+            },
+        )
+        .map(
+            |(i, line)| {
+                let mut columns = line
+                    .split(';')
+                    .map(str::trim);
+
+                let code = columns
+                    .next()
+                    .filter(
+                        |x| {
+                            x.len() == 4
+                                && x.chars()
+                                    .all(|x| x.is_ascii_digit())
+                        },
+                    )
+                    .ok_or(BankRegistryError::InvalidLine(i + 1))?;
+
+                let name = columns
+                    .next()
+                    .filter(|x| !x.is_empty())
+                    .ok_or(BankRegistryError::InvalidLine(i + 1))?;
+
+                let bic = columns
+                    .next()
+                    .filter(|x| !x.is_empty());
+
+                Ok(
+                    (
+                        code.to_string(),
+                        Bank {
+                            code: code.to_string(),
+                            name: name.to_string(),
+                            bic: bic.map(str::to_string),
+                        },
+                    ),
+                )
+            },
+        )
+        .collect()
+}
+
+/// Czech and Slovak banks by their codes.
+///
+/// The default registry holds the code lists embedded in the crate. Newer lists can be loaded from a file,
+/// invoices are rendered with the registry returned by `BankRegistry::global`.
+#[derive(Debug, Clone)]
+pub struct BankRegistry {
+    banks: HashMap<CountryCode, BTreeMap<String, Bank>>,
+}
+
+impl Default for BankRegistry {
+    fn default() -> Self {
+        let mut registry = Self::new();
+
+        for (country, list) in [
+            (
+                CountryCode::Cz,
+                CZ_BANK_CODES,
+            ),
+            (
+                CountryCode::Sk,
+                SK_BANK_CODES,
+            ),
+        ] {
+            registry
+                .load(
+                    country, list,
+                )
+                .expect("the embedded code lists are valid");
+        }
+
+        registry
+    }
+}
+
+impl BankRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self {
+            banks: HashMap::new(),
+        }
+    }
+
+    /// Replaces the banks of the country with the code list.
+    pub fn load(
+        &mut self,
+        country: CountryCode,
+        list: &str,
+    ) -> Result<(), BankRegistryError> {
+        self.banks
+            .insert(
+                country,
+                parse(list)?,
+            );
+
+        Ok(())
+    }
+
+    /// Replaces the banks of the country with the code list saved in the file.
+    pub fn load_file(
+        &mut self,
+        country: CountryCode,
+        path: impl AsRef<Path>,
+    ) -> Result<(), BankRegistryError> {
+        self.load(
+            country,
+            &std::fs::read_to_string(path)?,
+        )
+    }
+
+    pub fn get(
+        &self,
+        country: CountryCode,
+        code: &str,
+    ) -> Option<&Bank> {
+        self.banks
+            .get(&country)?
+            .get(code)
+    }
+
+    /// Returns the bank of a Czech or Slovak account.
+    pub fn find(
+        &self,
+        iban: &Iban,
+    ) -> Option<&Bank> {
+        let account = CzBankAccount::try_from(iban).ok()?;
+
+        self.get(
+            account.country(),
+            account.bank_code(),
+        )
+    }
+
+    /// Returns the registry used when rendering invoices.
+    pub fn global() -> RwLockReadGuard<'static, BankRegistry> {
+        REGISTRY
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Replaces the banks of the country in the global registry with the code list saved in the file.
+    pub fn update_global(
+        country: CountryCode,
+        path: impl AsRef<Path>,
+    ) -> Result<(), BankRegistryError> {
+        let banks = parse(&std::fs::read_to_string(path)?)?;
+
+        REGISTRY
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .banks
+            .insert(
+                country, banks,
+            );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedded_registry() {
+        let registry = BankRegistry::default();
+
+        let bank = registry
+            .find(
+                &"CZ6508000000192000145399"
+                    .parse()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            bank.name,
+            "Česká spořitelna, a.s."
+        );
+        assert_eq!(
+            bank.bic
+                .as_deref(),
+            Some("GIBACZPX")
+        );
+
+        assert_eq!(
+            registry
+                .get(
+                    CountryCode::Sk,
+                    "1200"
+                )
+                .map(
+                    |x| {
+                        x.name
+                            .as_str()
+                    }
+                ),
+            None
+        );
+        assert_eq!(
+            registry
+                .get(
+                    CountryCode::Sk,
+                    "1100"
+                )
+                .map(
+                    |x| {
+                        x.name
+                            .as_str()
+                    }
+                ),
+            Some("Tatra banka, a.s.")
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        let mut registry = BankRegistry::default();
+
+        registry
+            .load(
+                CountryCode::Cz,
+                "Kód směrového čísla;Název;SWIFT\n0800;Česká spořitelna, a.s.;GIBACZPX\n9999;Nová banka;\n",
+            )
+            .unwrap();
+
+        assert_eq!(
+            registry
+                .get(
+                    CountryCode::Cz,
+                    "9999"
+                )
+                .unwrap()
+                .bic,
+            None
+        );
+        assert!(
+            registry
+                .get(
+                    CountryCode::Cz,
+                    "0100"
+                )
+                .is_none()
+        );
+
+        assert!(
+            matches!(
+                registry.load(
+                    CountryCode::Cz,
+                    "Kód;Název;SWIFT\n0800;Česká spořitelna, a.s.;GIBACZPX\nbanka;;\n",
+                ),
+                Err(BankRegistryError::InvalidLine(3))
+            )
+        );
+    }
+}
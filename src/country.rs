@@ -0,0 +1,1146 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// ISO 3166-1 alpha-2 code of a country.
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default,
+)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CountryCode {
+    Ad,
+    Ae,
+    Af,
+    Ag,
+    Ai,
+    Al,
+    Am,
+    Ao,
+    Aq,
+    Ar,
+    As,
+    At,
+    Au,
+    Aw,
+    Ax,
+    Az,
+    Ba,
+    Bb,
+    Bd,
+    Be,
+    Bf,
+    Bg,
+    Bh,
+    Bi,
+    Bj,
+    Bl,
+    Bm,
+    Bn,
+    Bo,
+    Bq,
+    Br,
+    Bs,
+    Bt,
+    Bv,
+    Bw,
+    By,
+    Bz,
+    Ca,
+    Cc,
+    Cd,
+    Cf,
+    Cg,
+    Ch,
+    Ci,
+    Ck,
+    Cl,
+    Cm,
+    Cn,
+    Co,
+    Cr,
+    Cu,
+    Cv,
+    Cw,
+    Cx,
+    Cy,
+    #[default]
+    Cz,
+    De,
+    Dj,
+    Dk,
+    Dm,
+    Do,
+    Dz,
+    Ec,
+    Ee,
+    Eg,
+    Eh,
+    Er,
+    Es,
+    Et,
+    Fi,
+    Fj,
+    Fk,
+    Fm,
+    Fo,
+    Fr,
+    Ga,
+    Gb,
+    Gd,
+    Ge,
+    Gf,
+    Gg,
+    Gh,
+    Gi,
+    Gl,
+    Gm,
+    Gn,
+    Gp,
+    Gq,
+    Gr,
+    Gs,
+    Gt,
+    Gu,
+    Gw,
+    Gy,
+    Hk,
+    Hm,
+    Hn,
+    Hr,
+    Ht,
+    Hu,
+    Id,
+    Ie,
+    Il,
+    Im,
+    In,
+    Io,
+    Iq,
+    Ir,
+    Is,
+    It,
+    Je,
+    Jm,
+    Jo,
+    Jp,
+    Ke,
+    Kg,
+    Kh,
+    Ki,
+    Km,
+    Kn,
+    Kp,
+    Kr,
+    Kw,
+    Ky,
+    Kz,
+    La,
+    Lb,
+    Lc,
+    Li,
+    Lk,
+    Lr,
+    Ls,
+    Lt,
+    Lu,
+    Lv,
+    Ly,
+    Ma,
+    Mc,
+    Md,
+    Me,
+    Mf,
+    Mg,
+    Mh,
+    Mk,
+    Ml,
+    Mm,
+    Mn,
+    Mo,
+    Mp,
+    Mq,
+    Mr,
+    Ms,
+    Mt,
+    Mu,
+    Mv,
+    Mw,
+    Mx,
+    My,
+    Mz,
+    Na,
+    Nc,
+    Ne,
+    Nf,
+    Ng,
+    Ni,
+    Nl,
+    No,
+    Np,
+    Nr,
+    Nu,
+    Nz,
+    Om,
+    Pa,
+    Pe,
+    Pf,
+    Pg,
+    Ph,
+    Pk,
+    Pl,
+    Pm,
+    Pn,
+    Pr,
+    Ps,
+    Pt,
+    Pw,
+    Py,
+    Qa,
+    Re,
+    Ro,
+    Rs,
+    Ru,
+    Rw,
+    Sa,
+    Sb,
+    Sc,
+    Sd,
+    Se,
+    Sg,
+    Sh,
+    Si,
+    Sj,
+    Sk,
+    Sl,
+    Sm,
+    Sn,
+    So,
+    Sr,
+    Ss,
+    St,
+    Sv,
+    Sx,
+    Sy,
+    Sz,
+    Tc,
+    Td,
+    Tf,
+    Tg,
+    Th,
+    Tj,
+    Tk,
+    Tl,
+    Tm,
+    Tn,
+    To,
+    Tr,
+    Tt,
+    Tv,
+    Tw,
+    Tz,
+    Ua,
+    Ug,
+    Um,
+    Us,
+    Uy,
+    Uz,
+    Va,
+    Vc,
+    Ve,
+    Vg,
+    Vi,
+    Vn,
+    Vu,
+    Wf,
+    Ws,
+    Ye,
+    Yt,
+    Za,
+    Zm,
+    Zw,
+}
+
+#[derive(Debug)]
+pub struct UnknownCountryError;
+
+impl Display for UnknownCountryError {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(
+            f,
+            "Neznámý kód státu"
+        )
+    }
+}
+
+impl std::error::Error for UnknownCountryError {}
+
+impl CountryCode {
+    pub const ALL: [CountryCode; 249] = [
+        CountryCode::Ad,
+        CountryCode::Ae,
+        CountryCode::Af,
+        CountryCode::Ag,
+        CountryCode::Ai,
+        CountryCode::Al,
+        CountryCode::Am,
+        CountryCode::Ao,
+        CountryCode::Aq,
+        CountryCode::Ar,
+        CountryCode::As,
+        CountryCode::At,
+        CountryCode::Au,
+        CountryCode::Aw,
+        CountryCode::Ax,
+        CountryCode::Az,
+        CountryCode::Ba,
+        CountryCode::Bb,
+        CountryCode::Bd,
+        CountryCode::Be,
+        CountryCode::Bf,
+        CountryCode::Bg,
+        CountryCode::Bh,
+        CountryCode::Bi,
+        CountryCode::Bj,
+        CountryCode::Bl,
+        CountryCode::Bm,
+        CountryCode::Bn,
+        CountryCode::Bo,
+        CountryCode::Bq,
+        CountryCode::Br,
+        CountryCode::Bs,
+        CountryCode::Bt,
+        CountryCode::Bv,
+        CountryCode::Bw,
+        CountryCode::By,
+        CountryCode::Bz,
+        CountryCode::Ca,
+        CountryCode::Cc,
+        CountryCode::Cd,
+        CountryCode::Cf,
+        CountryCode::Cg,
+        CountryCode::Ch,
+        CountryCode::Ci,
+        CountryCode::Ck,
+        CountryCode::Cl,
+        CountryCode::Cm,
+        CountryCode::Cn,
+        CountryCode::Co,
+        CountryCode::Cr,
+        CountryCode::Cu,
+        CountryCode::Cv,
+        CountryCode::Cw,
+        CountryCode::Cx,
+        CountryCode::Cy,
+        CountryCode::Cz,
+        CountryCode::De,
+        CountryCode::Dj,
+        CountryCode::Dk,
+        CountryCode::Dm,
+        CountryCode::Do,
+        CountryCode::Dz,
+        CountryCode::Ec,
+        CountryCode::Ee,
+        CountryCode::Eg,
+        CountryCode::Eh,
+        CountryCode::Er,
+        CountryCode::Es,
+        CountryCode::Et,
+        CountryCode::Fi,
+        CountryCode::Fj,
+        CountryCode::Fk,
+        CountryCode::Fm,
+        CountryCode::Fo,
+        CountryCode::Fr,
+        CountryCode::Ga,
+        CountryCode::Gb,
+        CountryCode::Gd,
+        CountryCode::Ge,
+        CountryCode::Gf,
+        CountryCode::Gg,
+        CountryCode::Gh,
+        CountryCode::Gi,
+        CountryCode::Gl,
+        CountryCode::Gm,
+        CountryCode::Gn,
+        CountryCode::Gp,
+        CountryCode::Gq,
+        CountryCode::Gr,
+        CountryCode::Gs,
+        CountryCode::Gt,
+        CountryCode::Gu,
+        CountryCode::Gw,
+        CountryCode::Gy,
+        CountryCode::Hk,
+        CountryCode::Hm,
+        CountryCode::Hn,
+        CountryCode::Hr,
+        CountryCode::Ht,
+        CountryCode::Hu,
+        CountryCode::Id,
+        CountryCode::Ie,
+        CountryCode::Il,
+        CountryCode::Im,
+        CountryCode::In,
+        CountryCode::Io,
+        CountryCode::Iq,
+        CountryCode::Ir,
+        CountryCode::Is,
+        CountryCode::It,
+        CountryCode::Je,
+        CountryCode::Jm,
+        CountryCode::Jo,
+        CountryCode::Jp,
+        CountryCode::Ke,
+        CountryCode::Kg,
+        CountryCode::Kh,
+        CountryCode::Ki,
+        CountryCode::Km,
+        CountryCode::Kn,
+        CountryCode::Kp,
+        CountryCode::Kr,
+        CountryCode::Kw,
+        CountryCode::Ky,
+        CountryCode::Kz,
+        CountryCode::La,
+        CountryCode::Lb,
+        CountryCode::Lc,
+        CountryCode::Li,
+        CountryCode::Lk,
+        CountryCode::Lr,
+        CountryCode::Ls,
+        CountryCode::Lt,
+        CountryCode::Lu,
+        CountryCode::Lv,
+        CountryCode::Ly,
+        CountryCode::Ma,
+        CountryCode::Mc,
+        CountryCode::Md,
+        CountryCode::Me,
+        CountryCode::Mf,
+        CountryCode::Mg,
+        CountryCode::Mh,
+        CountryCode::Mk,
+        CountryCode::Ml,
+        CountryCode::Mm,
+        CountryCode::Mn,
+        CountryCode::Mo,
+        CountryCode::Mp,
+        CountryCode::Mq,
+        CountryCode::Mr,
+        CountryCode::Ms,
+        CountryCode::Mt,
+        CountryCode::Mu,
+        CountryCode::Mv,
+        CountryCode::Mw,
+        CountryCode::Mx,
+        CountryCode::My,
+        CountryCode::Mz,
+        CountryCode::Na,
+        CountryCode::Nc,
+        CountryCode::Ne,
+        CountryCode::Nf,
+        CountryCode::Ng,
+        CountryCode::Ni,
+        CountryCode::Nl,
+        CountryCode::No,
+        CountryCode::Np,
+        CountryCode::Nr,
+        CountryCode::Nu,
+        CountryCode::Nz,
+        CountryCode::Om,
+        CountryCode::Pa,
+        CountryCode::Pe,
+        CountryCode::Pf,
+        CountryCode::Pg,
+        CountryCode::Ph,
+        CountryCode::Pk,
+        CountryCode::Pl,
+        CountryCode::Pm,
+        CountryCode::Pn,
+        CountryCode::Pr,
+        CountryCode::Ps,
+        CountryCode::Pt,
+        CountryCode::Pw,
+        CountryCode::Py,
+        CountryCode::Qa,
+        CountryCode::Re,
+        CountryCode::Ro,
+        CountryCode::Rs,
+        CountryCode::Ru,
+        CountryCode::Rw,
+        CountryCode::Sa,
+        CountryCode::Sb,
+        CountryCode::Sc,
+        CountryCode::Sd,
+        CountryCode::Se,
+        CountryCode::Sg,
+        CountryCode::Sh,
+        CountryCode::Si,
+        CountryCode::Sj,
+        CountryCode::Sk,
+        CountryCode::Sl,
+        CountryCode::Sm,
+        CountryCode::Sn,
+        CountryCode::So,
+        CountryCode::Sr,
+        CountryCode::Ss,
+        CountryCode::St,
+        CountryCode::Sv,
+        CountryCode::Sx,
+        CountryCode::Sy,
+        CountryCode::Sz,
+        CountryCode::Tc,
+        CountryCode::Td,
+        CountryCode::Tf,
+        CountryCode::Tg,
+        CountryCode::Th,
+        CountryCode::Tj,
+        CountryCode::Tk,
+        CountryCode::Tl,
+        CountryCode::Tm,
+        CountryCode::Tn,
+        CountryCode::To,
+        CountryCode::Tr,
+        CountryCode::Tt,
+        CountryCode::Tv,
+        CountryCode::Tw,
+        CountryCode::Tz,
+        CountryCode::Ua,
+        CountryCode::Ug,
+        CountryCode::Um,
+        CountryCode::Us,
+        CountryCode::Uy,
+        CountryCode::Uz,
+        CountryCode::Va,
+        CountryCode::Vc,
+        CountryCode::Ve,
+        CountryCode::Vg,
+        CountryCode::Vi,
+        CountryCode::Vn,
+        CountryCode::Vu,
+        CountryCode::Wf,
+        CountryCode::Ws,
+        CountryCode::Ye,
+        CountryCode::Yt,
+        CountryCode::Za,
+        CountryCode::Zm,
+        CountryCode::Zw,
+    ];
+
+    /// Returns the two letter code.
+    pub fn code(&self) -> &'static str {
+        match self {
+            CountryCode::Ad => "AD",
+            CountryCode::Ae => "AE",
+            CountryCode::Af => "AF",
+            CountryCode::Ag => "AG",
+            CountryCode::Ai => "AI",
+            CountryCode::Al => "AL",
+            CountryCode::Am => "AM",
+            CountryCode::Ao => "AO",
+            CountryCode::Aq => "AQ",
+            CountryCode::Ar => "AR",
+            CountryCode::As => "AS",
+            CountryCode::At => "AT",
+            CountryCode::Au => "AU",
+            CountryCode::Aw => "AW",
+            CountryCode::Ax => "AX",
+            CountryCode::Az => "AZ",
+            CountryCode::Ba => "BA",
+            CountryCode::Bb => "BB",
+            CountryCode::Bd => "BD",
+            CountryCode::Be => "BE",
+            CountryCode::Bf => "BF",
+            CountryCode::Bg => "BG",
+            CountryCode::Bh => "BH",
+            CountryCode::Bi => "BI",
+            CountryCode::Bj => "BJ",
+            CountryCode::Bl => "BL",
+            CountryCode::Bm => "BM",
+            CountryCode::Bn => "BN",
+            CountryCode::Bo => "BO",
+            CountryCode::Bq => "BQ",
+            CountryCode::Br => "BR",
+            CountryCode::Bs => "BS",
+            CountryCode::Bt => "BT",
+            CountryCode::Bv => "BV",
+            CountryCode::Bw => "BW",
+            CountryCode::By => "BY",
+            CountryCode::Bz => "BZ",
+            CountryCode::Ca => "CA",
+            CountryCode::Cc => "CC",
+            CountryCode::Cd => "CD",
+            CountryCode::Cf => "CF",
+            CountryCode::Cg => "CG",
+            CountryCode::Ch => "CH",
+            CountryCode::Ci => "CI",
+            CountryCode::Ck => "CK",
+            CountryCode::Cl => "CL",
+            CountryCode::Cm => "CM",
+            CountryCode::Cn => "CN",
+            CountryCode::Co => "CO",
+            CountryCode::Cr => "CR",
+            CountryCode::Cu => "CU",
+            CountryCode::Cv => "CV",
+            CountryCode::Cw => "CW",
+            CountryCode::Cx => "CX",
+            CountryCode::Cy => "CY",
+            CountryCode::Cz => "CZ",
+            CountryCode::De => "DE",
+            CountryCode::Dj => "DJ",
+            CountryCode::Dk => "DK",
+            CountryCode::Dm => "DM",
+            CountryCode::Do => "DO",
+            CountryCode::Dz => "DZ",
+            CountryCode::Ec => "EC",
+            CountryCode::Ee => "EE",
+            CountryCode::Eg => "EG",
+            CountryCode::Eh => "EH",
+            CountryCode::Er => "ER",
+            CountryCode::Es => "ES",
+            CountryCode::Et => "ET",
+            CountryCode::Fi => "FI",
+            CountryCode::Fj => "FJ",
+            CountryCode::Fk => "FK",
+            CountryCode::Fm => "FM",
+            CountryCode::Fo => "FO",
+            CountryCode::Fr => "FR",
+            CountryCode::Ga => "GA",
+            CountryCode::Gb => "GB",
+            CountryCode::Gd => "GD",
+            CountryCode::Ge => "GE",
+            CountryCode::Gf => "GF",
+            CountryCode::Gg => "GG",
+            CountryCode::Gh => "GH",
+            CountryCode::Gi => "GI",
+            CountryCode::Gl => "GL",
+            CountryCode::Gm => "GM",
+            CountryCode::Gn => "GN",
+            CountryCode::Gp => "GP",
+            CountryCode::Gq => "GQ",
+            CountryCode::Gr => "GR",
+            CountryCode::Gs => "GS",
+            CountryCode::Gt => "GT",
+            CountryCode::Gu => "GU",
+            CountryCode::Gw => "GW",
+            CountryCode::Gy => "GY",
+            CountryCode::Hk => "HK",
+            CountryCode::Hm => "HM",
+            CountryCode::Hn => "HN",
+            CountryCode::Hr => "HR",
+            CountryCode::Ht => "HT",
+            CountryCode::Hu => "HU",
+            CountryCode::Id => "ID",
+            CountryCode::Ie => "IE",
+            CountryCode::Il => "IL",
+            CountryCode::Im => "IM",
+            CountryCode::In => "IN",
+            CountryCode::Io => "IO",
+            CountryCode::Iq => "IQ",
+            CountryCode::Ir => "IR",
+            CountryCode::Is => "IS",
+            CountryCode::It => "IT",
+            CountryCode::Je => "JE",
+            CountryCode::Jm => "JM",
+            CountryCode::Jo => "JO",
+            CountryCode::Jp => "JP",
+            CountryCode::Ke => "KE",
+            CountryCode::Kg => "KG",
+            CountryCode::Kh => "KH",
+            CountryCode::Ki => "KI",
+            CountryCode::Km => "KM",
+            CountryCode::Kn => "KN",
+            CountryCode::Kp => "KP",
+            CountryCode::Kr => "KR",
+            CountryCode::Kw => "KW",
+            CountryCode::Ky => "KY",
+            CountryCode::Kz => "KZ",
+            CountryCode::La => "LA",
+            CountryCode::Lb => "LB",
+            CountryCode::Lc => "LC",
+            CountryCode::Li => "LI",
+            CountryCode::Lk => "LK",
+            CountryCode::Lr => "LR",
+            CountryCode::Ls => "LS",
+            CountryCode::Lt => "LT",
+            CountryCode::Lu => "LU",
+            CountryCode::Lv => "LV",
+            CountryCode::Ly => "LY",
+            CountryCode::Ma => "MA",
+            CountryCode::Mc => "MC",
+            CountryCode::Md => "MD",
+            CountryCode::Me => "ME",
+            CountryCode::Mf => "MF",
+            CountryCode::Mg => "MG",
+            CountryCode::Mh => "MH",
+            CountryCode::Mk => "MK",
+            CountryCode::Ml => "ML",
+            CountryCode::Mm => "MM",
+            CountryCode::Mn => "MN",
+            CountryCode::Mo => "MO",
+            CountryCode::Mp => "MP",
+            CountryCode::Mq => "MQ",
+            CountryCode::Mr => "MR",
+            CountryCode::Ms => "MS",
+            CountryCode::Mt => "MT",
+            CountryCode::Mu => "MU",
+            CountryCode::Mv => "MV",
+            CountryCode::Mw => "MW",
+            CountryCode::Mx => "MX",
+            CountryCode::My => "MY",
+            CountryCode::Mz => "MZ",
+            CountryCode::Na => "NA",
+            CountryCode::Nc => "NC",
+            CountryCode::Ne => "NE",
+            CountryCode::Nf => "NF",
+            CountryCode::Ng => "NG",
+            CountryCode::Ni => "NI",
+            CountryCode::Nl => "NL",
+            CountryCode::No => "NO",
+            CountryCode::Np => "NP",
+            CountryCode::Nr => "NR",
+            CountryCode::Nu => "NU",
+            CountryCode::Nz => "NZ",
+            CountryCode::Om => "OM",
+            CountryCode::Pa => "PA",
+            CountryCode::Pe => "PE",
+            CountryCode::Pf => "PF",
+            CountryCode::Pg => "PG",
+            CountryCode::Ph => "PH",
+            CountryCode::Pk => "PK",
+            CountryCode::Pl => "PL",
+            CountryCode::Pm => "PM",
+            CountryCode::Pn => "PN",
+            CountryCode::Pr => "PR",
+            CountryCode::Ps => "PS",
+            CountryCode::Pt => "PT",
+            CountryCode::Pw => "PW",
+            CountryCode::Py => "PY",
+            CountryCode::Qa => "QA",
+            CountryCode::Re => "RE",
+            CountryCode::Ro => "RO",
+            CountryCode::Rs => "RS",
+            CountryCode::Ru => "RU",
+            CountryCode::Rw => "RW",
+            CountryCode::Sa => "SA",
+            CountryCode::Sb => "SB",
+            CountryCode::Sc => "SC",
+            CountryCode::Sd => "SD",
+            CountryCode::Se => "SE",
+            CountryCode::Sg => "SG",
+            CountryCode::Sh => "SH",
+            CountryCode::Si => "SI",
+            CountryCode::Sj => "SJ",
+            CountryCode::Sk => "SK",
+            CountryCode::Sl => "SL",
+            CountryCode::Sm => "SM",
+            CountryCode::Sn => "SN",
+            CountryCode::So => "SO",
+            CountryCode::Sr => "SR",
+            CountryCode::Ss => "SS",
+            CountryCode::St => "ST",
+            CountryCode::Sv => "SV",
+            CountryCode::Sx => "SX",
+            CountryCode::Sy => "SY",
+            CountryCode::Sz => "SZ",
+            CountryCode::Tc => "TC",
+            CountryCode::Td => "TD",
+            CountryCode::Tf => "TF",
+            CountryCode::Tg => "TG",
+            CountryCode::Th => "TH",
+            CountryCode::Tj => "TJ",
+            CountryCode::Tk => "TK",
+            CountryCode::Tl => "TL",
+            CountryCode::Tm => "TM",
+            CountryCode::Tn => "TN",
+            CountryCode::To => "TO",
+            CountryCode::Tr => "TR",
+            CountryCode::Tt => "TT",
+            CountryCode::Tv => "TV",
+            CountryCode::Tw => "TW",
+            CountryCode::Tz => "TZ",
+            CountryCode::Ua => "UA",
+            CountryCode::Ug => "UG",
+            CountryCode::Um => "UM",
+            CountryCode::Us => "US",
+            CountryCode::Uy => "UY",
+            CountryCode::Uz => "UZ",
+            CountryCode::Va => "VA",
+            CountryCode::Vc => "VC",
+            CountryCode::Ve => "VE",
+            CountryCode::Vg => "VG",
+            CountryCode::Vi => "VI",
+            CountryCode::Vn => "VN",
+            CountryCode::Vu => "VU",
+            CountryCode::Wf => "WF",
+            CountryCode::Ws => "WS",
+            CountryCode::Ye => "YE",
+            CountryCode::Yt => "YT",
+            CountryCode::Za => "ZA",
+            CountryCode::Zm => "ZM",
+            CountryCode::Zw => "ZW",
+        }
+    }
+
+    /// Returns whether the country is a member state of the EU.
+    pub fn is_eu(&self) -> bool {
+        matches!(
+            self,
+            CountryCode::At
+                | CountryCode::Be
+                | CountryCode::Bg
+                | CountryCode::Cy
+                | CountryCode::Cz
+                | CountryCode::De
+                | CountryCode::Dk
+                | CountryCode::Ee
+                | CountryCode::Es
+                | CountryCode::Fi
+                | CountryCode::Fr
+                | CountryCode::Gr
+                | CountryCode::Hr
+                | CountryCode::Hu
+                | CountryCode::Ie
+                | CountryCode::It
+                | CountryCode::Lt
+                | CountryCode::Lu
+                | CountryCode::Lv
+                | CountryCode::Mt
+                | CountryCode::Nl
+                | CountryCode::Pl
+                | CountryCode::Pt
+                | CountryCode::Ro
+                | CountryCode::Se
+                | CountryCode::Si
+                | CountryCode::Sk
+        )
+    }
+
+    /// Returns whether SEPA credit transfers reach the country, the EU and EEA states with their
+    /// territories and the other countries taking part in the SEPA schemes.
+    pub fn is_sepa(&self) -> bool {
+        self.is_eu()
+            || matches!(
+                self,
+                // EEA
+                CountryCode::Is
+                    | CountryCode::Li
+                    | CountryCode::No
+                    // Territories of the member states
+                    | CountryCode::Ax
+                    | CountryCode::Bl
+                    | CountryCode::Gf
+                    | CountryCode::Gi
+                    | CountryCode::Gp
+                    | CountryCode::Mf
+                    | CountryCode::Mq
+                    | CountryCode::Pm
+                    | CountryCode::Re
+                    | CountryCode::Yt
+                    // Other participants
+                    | CountryCode::Ad
+                    | CountryCode::Al
+                    | CountryCode::Ch
+                    | CountryCode::Gb
+                    | CountryCode::Gg
+                    | CountryCode::Im
+                    | CountryCode::Je
+                    | CountryCode::Mc
+                    | CountryCode::Md
+                    | CountryCode::Me
+                    | CountryCode::Mk
+                    | CountryCode::Rs
+                    | CountryCode::Sm
+                    | CountryCode::Va
+            )
+    }
+}
+
+impl CountryCode {
+    /// Returns the Czech name of the country, as printed on invoices.
+    pub fn name(&self) -> &'static str {
+        match self {
+            CountryCode::Ad => "Andorra",
+            CountryCode::Ae => "Spojené arabské emiráty",
+            CountryCode::Af => "Afghánistán",
+            CountryCode::Ag => "Antigua a Barbuda",
+            CountryCode::Ai => "Anguilla",
+            CountryCode::Al => "Albánie",
+            CountryCode::Am => "Arménie",
+            CountryCode::Ao => "Angola",
+            CountryCode::Aq => "Antarktida",
+            CountryCode::Ar => "Argentina",
+            CountryCode::As => "Americká Samoa",
+            CountryCode::At => "Rakousko",
+            CountryCode::Au => "Austrálie",
+            CountryCode::Aw => "Aruba",
+            CountryCode::Ax => "Alandy",
+            CountryCode::Az => "Ázerbájdžán",
+            CountryCode::Ba => "Bosna a Hercegovina",
+            CountryCode::Bb => "Barbados",
+            CountryCode::Bd => "Bangladéš",
+            CountryCode::Be => "Belgie",
+            CountryCode::Bf => "Burkina Faso",
+            CountryCode::Bg => "Bulharsko",
+            CountryCode::Bh => "Bahrajn",
+            CountryCode::Bi => "Burundi",
+            CountryCode::Bj => "Benin",
+            CountryCode::Bl => "Svatý Bartoloměj",
+            CountryCode::Bm => "Bermudy",
+            CountryCode::Bn => "Brunej",
+            CountryCode::Bo => "Bolívie",
+            CountryCode::Bq => "Bonaire, Svatý Eustach a Saba",
+            CountryCode::Br => "Brazílie",
+            CountryCode::Bs => "Bahamy",
+            CountryCode::Bt => "Bhútán",
+            CountryCode::Bv => "Bouvetův ostrov",
+            CountryCode::Bw => "Botswana",
+            CountryCode::By => "Bělorusko",
+            CountryCode::Bz => "Belize",
+            CountryCode::Ca => "Kanada",
+            CountryCode::Cc => "Kokosové ostrovy",
+            CountryCode::Cd => "Demokratická republika Kongo",
+            CountryCode::Cf => "Středoafrická republika",
+            CountryCode::Cg => "Kongo",
+            CountryCode::Ch => "Švýcarsko",
+            CountryCode::Ci => "Pobřeží slonoviny",
+            CountryCode::Ck => "Cookovy ostrovy",
+            CountryCode::Cl => "Chile",
+            CountryCode::Cm => "Kamerun",
+            CountryCode::Cn => "Čína",
+            CountryCode::Co => "Kolumbie",
+            CountryCode::Cr => "Kostarika",
+            CountryCode::Cu => "Kuba",
+            CountryCode::Cv => "Kapverdy",
+            CountryCode::Cw => "Curaçao",
+            CountryCode::Cx => "Vánoční ostrov",
+            CountryCode::Cy => "Kypr",
+            CountryCode::Cz => "Česká republika",
+            CountryCode::De => "Německo",
+            CountryCode::Dj => "Džibutsko",
+            CountryCode::Dk => "Dánsko",
+            CountryCode::Dm => "Dominika",
+            CountryCode::Do => "Dominikánská republika",
+            CountryCode::Dz => "Alžírsko",
+            CountryCode::Ec => "Ekvádor",
+            CountryCode::Ee => "Estonsko",
+            CountryCode::Eg => "Egypt",
+            CountryCode::Eh => "Západní Sahara",
+            CountryCode::Er => "Eritrea",
+            CountryCode::Es => "Španělsko",
+            CountryCode::Et => "Etiopie",
+            CountryCode::Fi => "Finsko",
+            CountryCode::Fj => "Fidži",
+            CountryCode::Fk => "Falklandy",
+            CountryCode::Fm => "Mikronésie",
+            CountryCode::Fo => "Faerské ostrovy",
+            CountryCode::Fr => "Francie",
+            CountryCode::Ga => "Gabon",
+            CountryCode::Gb => "Spojené království",
+            CountryCode::Gd => "Grenada",
+            CountryCode::Ge => "Gruzie",
+            CountryCode::Gf => "Francouzská Guyana",
+            CountryCode::Gg => "Guernsey",
+            CountryCode::Gh => "Ghana",
+            CountryCode::Gi => "Gibraltar",
+            CountryCode::Gl => "Grónsko",
+            CountryCode::Gm => "Gambie",
+            CountryCode::Gn => "Guinea",
+            CountryCode::Gp => "Guadeloupe",
+            CountryCode::Gq => "Rovníková Guinea",
+            CountryCode::Gr => "Řecko",
+            CountryCode::Gs => "Jižní Georgie a Jižní Sandwichovy ostrovy",
+            CountryCode::Gt => "Guatemala",
+            CountryCode::Gu => "Guam",
+            CountryCode::Gw => "Guinea-Bissau",
+            CountryCode::Gy => "Guyana",
+            CountryCode::Hk => "Hongkong",
+            CountryCode::Hm => "Heardův ostrov a McDonaldovy ostrovy",
+            CountryCode::Hn => "Honduras",
+            CountryCode::Hr => "Chorvatsko",
+            CountryCode::Ht => "Haiti",
+            CountryCode::Hu => "Maďarsko",
+            CountryCode::Id => "Indonésie",
+            CountryCode::Ie => "Irsko",
+            CountryCode::Il => "Izrael",
+            CountryCode::Im => "Ostrov Man",
+            CountryCode::In => "Indie",
+            CountryCode::Io => "Britské indickooceánské území",
+            CountryCode::Iq => "Irák",
+            CountryCode::Ir => "Írán",
+            CountryCode::Is => "Island",
+            CountryCode::It => "Itálie",
+            CountryCode::Je => "Jersey",
+            CountryCode::Jm => "Jamajka",
+            CountryCode::Jo => "Jordánsko",
+            CountryCode::Jp => "Japonsko",
+            CountryCode::Ke => "Keňa",
+            CountryCode::Kg => "Kyrgyzstán",
+            CountryCode::Kh => "Kambodža",
+            CountryCode::Ki => "Kiribati",
+            CountryCode::Km => "Komory",
+            CountryCode::Kn => "Svatý Kryštof a Nevis",
+            CountryCode::Kp => "Korejská lidově demokratická republika",
+            CountryCode::Kr => "Korejská republika",
+            CountryCode::Kw => "Kuvajt",
+            CountryCode::Ky => "Kajmanské ostrovy",
+            CountryCode::Kz => "Kazachstán",
+            CountryCode::La => "Laos",
+            CountryCode::Lb => "Libanon",
+            CountryCode::Lc => "Svatá Lucie",
+            CountryCode::Li => "Lichtenštejnsko",
+            CountryCode::Lk => "Srí Lanka",
+            CountryCode::Lr => "Libérie",
+            CountryCode::Ls => "Lesotho",
+            CountryCode::Lt => "Litva",
+            CountryCode::Lu => "Lucembursko",
+            CountryCode::Lv => "Lotyšsko",
+            CountryCode::Ly => "Libye",
+            CountryCode::Ma => "Maroko",
+            CountryCode::Mc => "Monako",
+            CountryCode::Md => "Moldavsko",
+            CountryCode::Me => "Černá Hora",
+            CountryCode::Mf => "Svatý Martin (francouzská část)",
+            CountryCode::Mg => "Madagaskar",
+            CountryCode::Mh => "Marshallovy ostrovy",
+            CountryCode::Mk => "Severní Makedonie",
+            CountryCode::Ml => "Mali",
+            CountryCode::Mm => "Myanmar",
+            CountryCode::Mn => "Mongolsko",
+            CountryCode::Mo => "Macao",
+            CountryCode::Mp => "Severní Mariany",
+            CountryCode::Mq => "Martinik",
+            CountryCode::Mr => "Mauritánie",
+            CountryCode::Ms => "Montserrat",
+            CountryCode::Mt => "Malta",
+            CountryCode::Mu => "Mauricius",
+            CountryCode::Mv => "Maledivy",
+            CountryCode::Mw => "Malawi",
+            CountryCode::Mx => "Mexiko",
+            CountryCode::My => "Malajsie",
+            CountryCode::Mz => "Mosambik",
+            CountryCode::Na => "Namibie",
+            CountryCode::Nc => "Nová Kaledonie",
+            CountryCode::Ne => "Niger",
+            CountryCode::Nf => "Norfolk",
+            CountryCode::Ng => "Nigérie",
+            CountryCode::Ni => "Nikaragua",
+            CountryCode::Nl => "Nizozemsko",
+            CountryCode::No => "Norsko",
+            CountryCode::Np => "Nepál",
+            CountryCode::Nr => "Nauru",
+            CountryCode::Nu => "Niue",
+            CountryCode::Nz => "Nový Zéland",
+            CountryCode::Om => "Omán",
+            CountryCode::Pa => "Panama",
+            CountryCode::Pe => "Peru",
+            CountryCode::Pf => "Francouzská Polynésie",
+            CountryCode::Pg => "Papua Nová Guinea",
+            CountryCode::Ph => "Filipíny",
+            CountryCode::Pk => "Pákistán",
+            CountryCode::Pl => "Polsko",
+            CountryCode::Pm => "Saint-Pierre a Miquelon",
+            CountryCode::Pn => "Pitcairnovy ostrovy",
+            CountryCode::Pr => "Portoriko",
+            CountryCode::Ps => "Palestina",
+            CountryCode::Pt => "Portugalsko",
+            CountryCode::Pw => "Palau",
+            CountryCode::Py => "Paraguay",
+            CountryCode::Qa => "Katar",
+            CountryCode::Re => "Réunion",
+            CountryCode::Ro => "Rumunsko",
+            CountryCode::Rs => "Srbsko",
+            CountryCode::Ru => "Rusko",
+            CountryCode::Rw => "Rwanda",
+            CountryCode::Sa => "Saúdská Arábie",
+            CountryCode::Sb => "Šalamounovy ostrovy",
+            CountryCode::Sc => "Seychely",
+            CountryCode::Sd => "Súdán",
+            CountryCode::Se => "Švédsko",
+            CountryCode::Sg => "Singapur",
+            CountryCode::Sh => "Svatá Helena, Ascension a Tristan da Cunha",
+            CountryCode::Si => "Slovinsko",
+            CountryCode::Sj => "Špicberky a Jan Mayen",
+            CountryCode::Sk => "Slovensko",
+            CountryCode::Sl => "Sierra Leone",
+            CountryCode::Sm => "San Marino",
+            CountryCode::Sn => "Senegal",
+            CountryCode::So => "Somálsko",
+            CountryCode::Sr => "Surinam",
+            CountryCode::Ss => "Jižní Súdán",
+            CountryCode::St => "Svatý Tomáš a Princův ostrov",
+            CountryCode::Sv => "Salvador",
+            CountryCode::Sx => "Svatý Martin (nizozemská část)",
+            CountryCode::Sy => "Sýrie",
+            CountryCode::Sz => "Eswatini",
+            CountryCode::Tc => "Turks a Caicos",
+            CountryCode::Td => "Čad",
+            CountryCode::Tf => "Francouzská jižní a antarktická území",
+            CountryCode::Tg => "Togo",
+            CountryCode::Th => "Thajsko",
+            CountryCode::Tj => "Tádžikistán",
+            CountryCode::Tk => "Tokelau",
+            CountryCode::Tl => "Východní Timor",
+            CountryCode::Tm => "Turkmenistán",
+            CountryCode::Tn => "Tunisko",
+            CountryCode::To => "Tonga",
+            CountryCode::Tr => "Turecko",
+            CountryCode::Tt => "Trinidad a Tobago",
+            CountryCode::Tv => "Tuvalu",
+            CountryCode::Tw => "Tchaj-wan",
+            CountryCode::Tz => "Tanzanie",
+            CountryCode::Ua => "Ukrajina",
+            CountryCode::Ug => "Uganda",
+            CountryCode::Um => "Menší odlehlé ostrovy USA",
+            CountryCode::Us => "Spojené státy americké",
+            CountryCode::Uy => "Uruguay",
+            CountryCode::Uz => "Uzbekistán",
+            CountryCode::Va => "Vatikán",
+            CountryCode::Vc => "Svatý Vincenc a Grenadiny",
+            CountryCode::Ve => "Venezuela",
+            CountryCode::Vg => "Britské Panenské ostrovy",
+            CountryCode::Vi => "Americké Panenské ostrovy",
+            CountryCode::Vn => "Vietnam",
+            CountryCode::Vu => "Vanuatu",
+            CountryCode::Wf => "Wallis a Futuna",
+            CountryCode::Ws => "Samoa",
+            CountryCode::Ye => "Jemen",
+            CountryCode::Yt => "Mayotte",
+            CountryCode::Za => "Jihoafrická republika",
+            CountryCode::Zm => "Zambie",
+            CountryCode::Zw => "Zimbabwe",
+        }
+    }
+}
+
+impl Display for CountryCode {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for CountryCode {
+    type Err = UnknownCountryError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+
+        CountryCode::ALL
+            .into_iter()
+            .find(
+                |x| {
+                    x.code()
+                        .eq_ignore_ascii_case(value)
+                },
+            )
+            .ok_or(UnknownCountryError)
+    }
+}
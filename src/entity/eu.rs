@@ -3,8 +3,9 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::address::Address;
-use crate::ares;
+use crate::bank_account::BankAccount;
 use crate::registration_number::RegistrationNumber;
+use crate::vat_number::VatNumber;
 
 pub enum Error {}
 
@@ -14,7 +15,13 @@ pub struct Entity {
     pub name: String,
 
     pub address: Address,
-    pub vat_number: Option<String>,
+    pub vat_number: Option<VatNumber>,
+    /// Accounts receiving payments, the invoice uses the one in its currency
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub accounts: Vec<BankAccount>,
 }
 
 impl Entity {
@@ -22,29 +29,44 @@ impl Entity {
         identifier: RegistrationNumber,
         name: impl Into<String>,
         address: Address,
-        vat_number: Option<impl Into<String>>,
+        vat_number: Option<VatNumber>,
     ) -> Self {
         Self {
             identifier,
             name: name.into(),
             address,
-            vat_number: vat_number.map(|s| s.into()),
+            vat_number,
+            accounts: Vec::new(),
         }
     }
 
-    pub fn to_html(&self) -> maud::Markup {
+    /// Returns true if the entity is registered for VAT.
+    pub fn is_vat_payer(&self) -> bool {
+        self.vat_number
+            .is_some()
+    }
+
+    /// Renders the entity, the country is shown when it differs from the other party's.
+    pub fn to_html(
+        &self,
+        show_country: bool,
+    ) -> maud::Markup {
         html!(
             div class="entity-info" {
                 strong class="entity-name" { (self.name) }
 
                 div class="entity-address" {
-                    p class="text-grayed" { (self.address.get_first_line()) };
-                    p class="text-grayed" { (self.address.get_second_line()) };
+                    @for line in self.address.lines() {
+                        p class="text-grayed" { (line) };
+                    }
+                    @if show_country {
+                        p class="text-grayed" { (self.address.country.name()) };
+                    }
                 }
 
                 div class="entity-billing-info" {
                     div class="space-between" {
-                        p class="text-grayed" { "IČO" };
+                        p class="text-grayed" { (self.identifier.label()) };
                         p { (self.identifier) };
                     }
 
@@ -62,8 +84,17 @@ impl Entity {
     }
 }
 
+#[cfg(feature = "async")]
+impl Entity {
+    /// Fetches the entity from ARES registry without blocking
+    pub async fn from_ares(number: RegistrationNumber) -> Result<Self, crate::ares::Error> {
+        crate::ares::fetch_from_ares_async(number).await
+    }
+}
+
+#[cfg(feature = "blocking")]
 impl TryFrom<RegistrationNumber> for Entity {
-    type Error = ares::Error;
+    type Error = crate::ares::Error;
 
     fn try_from(value: RegistrationNumber) -> Result<Self, Self::Error> {
         use crate::ares;
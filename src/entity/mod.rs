@@ -1,24 +1,188 @@
 use std::fmt::Display;
 
+use iso_currency::Currency;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::bank_account::BankAccount;
+
 pub mod eu;
 pub mod us;
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Contractor or client of an invoice.
+///
+/// The unit variants `Us`, `Canada`, `China`, `India`, `Japan`, `Korea`, `Australia`, `Brazil`,
+/// `Argentina` and `Africa` of the older versions were placeholders without any data, invoices
+/// couldn't hold them. They were removed, `Us` carries the US entity now.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "EntityRecord")]
 pub enum Entity {
     Eu(eu::Entity),
-    Us,
-    Canada,
-    China,
-    India,
-    Japan,
-    Korea,
-    Australia,
-    Brazil,
-    Argentina,
-    Africa,
+    Us(us::Entity),
+}
+
+/// Stored form of the entity, also accepts the bare EU entity of the invoices saved
+/// before the invoices could hold other entities
+#[derive(Deserialize)]
+enum EntityRecord {
+    Eu(eu::Entity),
+    Us(us::Entity),
+    #[serde(untagged)]
+    Legacy(eu::Entity),
+}
+
+impl From<EntityRecord> for Entity {
+    fn from(record: EntityRecord) -> Self {
+        match record {
+            EntityRecord::Eu(entity) | EntityRecord::Legacy(entity) => Entity::Eu(entity),
+            EntityRecord::Us(entity) => Entity::Us(entity),
+        }
+    }
+}
+
+impl Entity {
+    pub fn name(&self) -> &str {
+        match self {
+            Entity::Eu(entity) => &entity.name,
+            Entity::Us(entity) => &entity.name,
+        }
+    }
+
+    /// Returns the registration number, or the tax identification number of an US entity.
+    pub fn identifier(&self) -> String {
+        match self {
+            Entity::Eu(entity) => {
+                entity
+                    .identifier
+                    .to_string()
+            }
+            Entity::Us(entity) => {
+                entity
+                    .tax_id
+                    .to_string()
+            }
+        }
+    }
+
+    /// Returns true if the entity is registered for VAT, there is no VAT in the US.
+    pub fn is_vat_payer(&self) -> bool {
+        match self {
+            Entity::Eu(entity) => entity.is_vat_payer(),
+            Entity::Us(_) => false,
+        }
+    }
+
+    /// Returns the ISO 3166-1 code of the country the entity is registered in.
+    pub fn country_code(&self) -> &'static str {
+        match self {
+            Entity::Eu(entity) => {
+                entity
+                    .address
+                    .country
+                    .code()
+            }
+            Entity::Us(_) => "US",
+        }
+    }
+
+    /// Returns the lines of the address as printed on the invoice.
+    ///
+    /// The country is added when `show_country` is set, i.e. the parties are from different countries.
+    pub fn address_lines(
+        &self,
+        show_country: bool,
+    ) -> Vec<String> {
+        let (mut lines, country) = match self {
+            Entity::Eu(entity) => {
+                (
+                    entity
+                        .address
+                        .lines(),
+                    entity
+                        .address
+                        .country
+                        .name(),
+                )
+            }
+            Entity::Us(entity) => {
+                (
+                    vec![
+                        entity
+                            .address
+                            .get_first_line(),
+                        entity
+                            .address
+                            .get_second_line(),
+                    ],
+                    us::COUNTRY_NAME,
+                )
+            }
+        };
+
+        if show_country {
+            lines.push(country.to_string());
+        }
+
+        lines
+    }
+
+    pub fn accounts(&self) -> &[BankAccount] {
+        match self {
+            Entity::Eu(entity) => &entity.accounts,
+            Entity::Us(entity) => &entity.accounts,
+        }
+    }
+
+    /// Returns the first account in the currency.
+    pub fn account(
+        &self,
+        currency: Currency,
+    ) -> Option<&BankAccount> {
+        self.accounts()
+            .iter()
+            .find(|x| x.currency == currency)
+    }
+
+    pub fn as_eu(&self) -> Option<&eu::Entity> {
+        match self {
+            Entity::Eu(entity) => Some(entity),
+            Entity::Us(_) => None,
+        }
+    }
+
+    pub fn to_html(
+        &self,
+        show_country: bool,
+    ) -> maud::Markup {
+        match self {
+            Entity::Eu(entity) => entity.to_html(show_country),
+            Entity::Us(entity) => entity.to_html(show_country),
+        }
+    }
+}
+
+impl From<eu::Entity> for Entity {
+    fn from(entity: eu::Entity) -> Self {
+        Entity::Eu(entity)
+    }
+}
+
+impl From<us::Entity> for Entity {
+    fn from(entity: us::Entity) -> Self {
+        Entity::Us(entity)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl TryFrom<crate::registration_number::RegistrationNumber> for Entity {
+    type Error = crate::ares::Error;
+
+    /// Fetches the entity from ARES
+    fn try_from(
+        value: crate::registration_number::RegistrationNumber
+    ) -> Result<Self, Self::Error> {
+        eu::Entity::try_from(value).map(Entity::Eu)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
@@ -1,14 +1,63 @@
 use std::fmt::Display;
 use std::str::FromStr;
 
+use maud::html;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::bank_account::BankAccount;
+
+/// Two letter codes of the states, the federal district, the territories and the military post offices
+const STATES: [&str; 59] = [
+    "AL", "AK", "AZ", "AR", "CA", "CO", "CT", "DE", "FL", "GA", "HI", "ID", "IL", "IN", "IA", "KS",
+    "KY", "LA", "ME", "MD", "MA", "MI", "MN", "MS", "MO", "MT", "NE", "NV", "NH", "NJ", "NM", "NY",
+    "NC", "ND", "OH", "OK", "OR", "PA", "RI", "SC", "SD", "TN", "TX", "UT", "VT", "VA", "WA", "WV",
+    "WI", "WY", "DC", "AS", "GU", "MP", "PR", "VI", "AA", "AE", "AP",
+];
+
+/// Czech name of the country, as printed on invoices
+pub const COUNTRY_NAME: &str = "Spojené státy americké";
+
+/// US taxpayer identification number of the entity
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub enum Vat {
+    /// Taxpayer identification number of an individual, e.g. SSN
     Tin(Tin),
+    /// Employer identification number of a company
     Ein(Ein),
 }
 
+impl Vat {
+    /// Returns the name of the number used on invoices.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Vat::Tin(_) => "TIN",
+            Vat::Ein(_) => "EIN",
+        }
+    }
+
+    pub fn get(&self) -> &str {
+        match self {
+            Vat::Tin(tin) => tin.get(),
+            Vat::Ein(ein) => ein.get(),
+        }
+    }
+}
+
+impl Display for Vat {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.write_str(self.get())
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Error {
     Invalid,
+    InvalidZip,
+    InvalidState,
 }
 
 impl Display for Error {
@@ -23,12 +72,29 @@ impl Display for Error {
                     "Invalid TIN/EIN"
                 )
             }
+            Self::InvalidZip => {
+                write!(
+                    f,
+                    "Invalid ZIP code"
+                )
+            }
+            Self::InvalidState => {
+                write!(
+                    f,
+                    "Invalid state code"
+                )
+            }
         }
     }
 }
 
 impl std::error::Error for Error {}
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(
+    try_from = "String",
+    into = "String"
+)]
 pub struct Tin(String);
 
 impl Tin {
@@ -37,6 +103,29 @@ impl Tin {
     }
 }
 
+impl Display for Tin {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl TryFrom<String> for Tin {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Tin> for String {
+    fn from(value: Tin) -> Self {
+        value.to_string()
+    }
+}
+
 impl FromStr for Tin {
     type Err = Error;
 
@@ -84,6 +173,11 @@ impl FromStr for Tin {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(
+    try_from = "String",
+    into = "String"
+)]
 pub struct Ein(String);
 
 impl Ein {
@@ -92,6 +186,29 @@ impl Ein {
     }
 }
 
+impl Display for Ein {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl TryFrom<String> for Ein {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Ein> for String {
+    fn from(value: Ein) -> Self {
+        value.to_string()
+    }
+}
+
 impl FromStr for Ein {
     type Err = Error;
 
@@ -134,6 +251,246 @@ impl FromStr for Ein {
     }
 }
 
+/// ZIP code, optionally in the ZIP+4 form `12345-6789`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(
+    try_from = "String",
+    into = "String"
+)]
+pub struct Zip {
+    code: String,
+    plus_four: Option<String>,
+}
+
+impl Zip {
+    /// Returns the five digit code.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns the four digit add-on of ZIP+4.
+    pub fn plus_four(&self) -> Option<&str> {
+        self.plus_four
+            .as_deref()
+    }
+}
+
+impl Display for Zip {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match &self.plus_four {
+            Some(plus_four) => {
+                write!(
+                    f,
+                    "{}-{}",
+                    self.code, plus_four
+                )
+            }
+            None => f.write_str(&self.code),
+        }
+    }
+}
+
+impl FromStr for Zip {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = |value: &str, len: usize| {
+            value.len() == len
+                && value
+                    .chars()
+                    .all(|c| c.is_ascii_digit())
+        };
+
+        let (code, plus_four) = match s
+            .trim()
+            .split_once('-')
+        {
+            Some((code, plus_four)) => {
+                (
+                    code,
+                    Some(plus_four),
+                )
+            }
+            None => {
+                (
+                    s.trim(),
+                    None,
+                )
+            }
+        };
+
+        if !digits(
+            code, 5,
+        ) || plus_four.is_some_and(
+            |x| {
+                !digits(
+                    x, 4,
+                )
+            },
+        ) {
+            return Err(Error::InvalidZip);
+        }
+
+        Ok(
+            Zip {
+                code: code.to_string(),
+                plus_four: plus_four.map(|x| x.to_string()),
+            },
+        )
+    }
+}
+
+impl TryFrom<String> for Zip {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Zip> for String {
+    fn from(value: Zip) -> Self {
+        value.to_string()
+    }
+}
+
+/// US postal address
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(try_from = "AddressRecord")]
+pub struct Address {
+    /// Street address including the house number, e.g. `1600 Amphitheatre Parkway`
+    pub street: String,
+    pub city: String,
+    state: String,
+    pub zip: Zip,
+}
+
+/// Stored form of the address, the state is validated when it's loaded
+#[derive(Deserialize)]
+struct AddressRecord {
+    street: String,
+    city: String,
+    state: String,
+    zip: Zip,
+}
+
+impl TryFrom<AddressRecord> for Address {
+    type Error = Error;
+
+    fn try_from(record: AddressRecord) -> Result<Self, Self::Error> {
+        Self::new(
+            record.street,
+            record.city,
+            &record.state,
+            record.zip,
+        )
+    }
+}
+
+impl Address {
+    pub fn new(
+        street: impl Into<String>,
+        city: impl Into<String>,
+        state: &str,
+        zip: Zip,
+    ) -> Result<Self, Error> {
+        let state = state
+            .trim()
+            .to_uppercase();
+
+        if !STATES.contains(&state.as_str()) {
+            return Err(Error::InvalidState);
+        }
+
+        Ok(
+            Self {
+                street: street.into(),
+                city: city.into(),
+                state,
+                zip,
+            },
+        )
+    }
+
+    /// Returns the two letter code of the state.
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /// Get the first line of the address
+    pub fn get_first_line(&self) -> String {
+        self.street
+            .clone()
+    }
+
+    /// Get the second line of the address, e.g. `Mountain View, CA 94043-1351`
+    pub fn get_second_line(&self) -> String {
+        format!(
+            "{}, {} {}",
+            self.city, self.state, self.zip
+        )
+    }
+}
+
+/// Entity registered in the United States
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Entity {
+    pub name: String,
+    pub address: Address,
+    pub tax_id: Vat,
+    /// Accounts receiving payments, the invoice uses the one in its currency
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub accounts: Vec<BankAccount>,
+}
+
+impl Entity {
+    pub fn new(
+        name: impl Into<String>,
+        address: Address,
+        tax_id: Vat,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            address,
+            tax_id,
+            accounts: Vec::new(),
+        }
+    }
+
+    /// Renders the entity, the country is shown when it differs from the other party's.
+    pub fn to_html(
+        &self,
+        show_country: bool,
+    ) -> maud::Markup {
+        html!(
+            div class="entity-info" {
+                strong class="entity-name" { (self.name) }
+
+                div class="entity-address" {
+                    p class="text-grayed" { (self.address.get_first_line()) };
+                    p class="text-grayed" { (self.address.get_second_line()) };
+                    @if show_country {
+                        p class="text-grayed" { (COUNTRY_NAME) };
+                    }
+                }
+
+                div class="entity-billing-info" {
+                    div class="space-between" {
+                        p class="text-grayed" { (self.tax_id.label()) };
+                        p { (self.tax_id) };
+                    }
+                }
+            }
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -196,4 +553,90 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_address() {
+        let address = Address::new(
+            "1600 Amphitheatre Parkway",
+            "Mountain View",
+            "ca",
+            "94043-1351"
+                .parse()
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            address.get_second_line(),
+            "Mountain View, CA 94043-1351"
+        );
+        assert_eq!(
+            Address::new(
+                "1 Main St",
+                "Springfield",
+                "XX",
+                "12345"
+                    .parse()
+                    .unwrap(),
+            ),
+            Err(Error::InvalidState)
+        );
+
+        for zip in [
+            "1234",
+            "12345-678",
+            "12345-67890",
+            "ABCDE",
+        ] {
+            assert_eq!(
+                zip.parse::<Zip>(),
+                Err(Error::InvalidZip)
+            );
+        }
+    }
+
+    #[test]
+    fn test_serde() {
+        let entity = Entity::new(
+            "Google LLC",
+            Address::new(
+                "1600 Amphitheatre Parkway",
+                "Mountain View",
+                "CA",
+                "94043-1351"
+                    .parse()
+                    .unwrap(),
+            )
+            .unwrap(),
+            Vat::Ein(
+                "77-0493581"
+                    .parse()
+                    .unwrap(),
+            ),
+        );
+        let stored = serde_json::to_string(&entity).unwrap();
+
+        assert!(stored.contains(r#""zip":"94043-1351""#));
+        assert_eq!(
+            serde_json::from_str::<Entity>(&stored).unwrap(),
+            entity
+        );
+
+        for (valid, invalid) in [
+            (
+                r#""Ein":"77-0493581""#,
+                r#""Ein":"77-049358""#,
+            ),
+            (
+                r#""zip":"94043-1351""#,
+                r#""zip":"9404""#,
+            ),
+            (
+                r#""state":"CA""#,
+                r#""state":"XX""#,
+            ),
+        ] {
+            assert!(serde_json::from_str::<Entity>(&stored.replace(valid, invalid)).is_err());
+        }
+    }
 }
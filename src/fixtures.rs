@@ -0,0 +1,84 @@
+use chrono::NaiveDate;
+use iban::Iban;
+use iso_currency::Currency;
+use rust_decimal::Decimal;
+
+use crate::address::Address;
+use crate::entity::eu;
+use crate::entity::Entity;
+use crate::invoice::Invoice;
+use crate::invoice::InvoiceItem;
+use crate::invoice::InvoiceItemType;
+use crate::payment_method::PaymentMethod;
+use crate::registration_number::RegistrationNumber;
+
+/// Date of issue of the test invoices
+pub(crate) fn date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(
+        2024, 3, 1,
+    )
+    .unwrap()
+}
+
+/// Account of Česká spořitelna with a valid checksum
+pub(crate) fn iban() -> Iban {
+    "CZ6508000000192000145399"
+        .parse()
+        .unwrap()
+}
+
+/// Subject seated in Prague, the country of the address is the one of the registration number
+pub(crate) fn entity(identifier: &str) -> eu::Entity {
+    let identifier: RegistrationNumber = identifier
+        .parse()
+        .unwrap();
+
+    eu::Entity::new(
+        identifier.clone(),
+        "Firma s.r.o.",
+        Address::new(
+            identifier.country(),
+            "Praha",
+            "Husova",
+            "12000",
+            "123/4",
+        ),
+        None,
+    )
+}
+
+/// One piece of work for the price
+pub(crate) fn items(price: i64) -> Vec<InvoiceItem> {
+    vec![
+        InvoiceItem::new(
+            InvoiceItemType::Quantity(1),
+            "Programování",
+            Decimal::new(
+                price, 0,
+            ),
+        ),
+    ]
+}
+
+/// Invoice number 202401 for 1000 in the currency, issued and due on [`date`]
+pub(crate) fn invoice(
+    contractor: impl Into<Entity>,
+    client: impl Into<Entity>,
+    iban: Option<Iban>,
+    currency: Currency,
+) -> Invoice {
+    Invoice::new(
+        Decimal::new(
+            202401, 0,
+        ),
+        contractor,
+        client,
+        iban,
+        PaymentMethod::BankTransfer("202401".to_string()),
+        items(1000),
+        date(),
+        date(),
+        currency,
+        None::<&str>,
+    )
+}
@@ -0,0 +1,490 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use reqwest::header::HeaderMap;
+use reqwest::header::RETRY_AFTER;
+use reqwest::StatusCode;
+
+use crate::registration_number::RegistrationNumber;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The connection failed or timed out
+    TransportError(reqwest::Error),
+    JsonError(serde_json::Error),
+    XmlError(quick_xml::DeError),
+    /// The registry doesn't know the registration number
+    NotFound(RegistrationNumber),
+    /// Too many requests, the registry refused to answer even after retrying
+    RateLimited,
+    /// The registry failed with a 5xx status even after retrying
+    ServerError(u16),
+    /// The registry answered with an unexpected status
+    BadContent(u16),
+    /// The service answered, but refused to process the request, e.g. VIES with `MS_UNAVAILABLE`
+    ServiceError(String),
+    /// The service doesn't handle numbers of the country, e.g. RPO with a Czech IČO
+    UnsupportedCountry(String),
+    /// The registry answered with a country code that isn't in ISO 3166-1
+    UnknownCountry(String),
+    /// The request needs an API key and none is configured
+    MissingApiKey,
+}
+
+impl Display for Error {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Error::TransportError(e) => {
+                write!(
+                    f,
+                    "Transport error: {}",
+                    e
+                )
+            }
+            Error::JsonError(e) => {
+                write!(
+                    f,
+                    "JSON error: {}",
+                    e
+                )
+            }
+            Error::XmlError(e) => {
+                write!(
+                    f,
+                    "XML error: {}",
+                    e
+                )
+            }
+            Error::NotFound(number) => {
+                write!(
+                    f,
+                    "Subject {} was not found in the registry",
+                    number
+                )
+            }
+            Error::RateLimited => {
+                write!(
+                    f,
+                    "Registry rate limit exceeded"
+                )
+            }
+            Error::ServerError(status) => {
+                write!(
+                    f,
+                    "Registry server error: {}",
+                    status
+                )
+            }
+            Error::BadContent(status) => {
+                write!(
+                    f,
+                    "Bad content, status: {}",
+                    status
+                )
+            }
+            Error::ServiceError(error) => {
+                write!(
+                    f,
+                    "Service error: {}",
+                    error
+                )
+            }
+            Error::UnsupportedCountry(number) => {
+                write!(
+                    f,
+                    "The service doesn't handle the number {}",
+                    number
+                )
+            }
+            Error::UnknownCountry(code) => {
+                write!(
+                    f,
+                    "Unknown country code: {}",
+                    code
+                )
+            }
+            Error::MissingApiKey => {
+                write!(
+                    f,
+                    "The request needs an API key, none is configured"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Retry policy of the registry clients
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Retry {
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled with every next one
+    pub backoff: Duration,
+    /// Longest delay requested in `Retry-After` worth waiting for
+    pub max_retry_after: Duration,
+}
+
+impl Retry {
+    /// Maps an unsuccessful response to an error,
+    /// returns the delay before the next attempt if the request should be retried.
+    ///
+    /// A `Retry-After` longer than `max_retry_after` isn't waited for, it's reported as [`Error::RateLimited`].
+    ///
+    /// `number` is the looked up subject, 404 is reported as [`Error::NotFound`] for it.
+    pub(crate) fn delay(
+        &self,
+        number: Option<&RegistrationNumber>,
+        status: StatusCode,
+        headers: &HeaderMap,
+        attempt: u32,
+    ) -> Result<Duration, Error> {
+        let error = match (
+            status, number,
+        ) {
+            (StatusCode::NOT_FOUND, Some(number)) => return Err(Error::NotFound(number.clone())),
+            (StatusCode::TOO_MANY_REQUESTS, _) => Error::RateLimited,
+            (status, _) if status.is_server_error() => Error::ServerError(status.as_u16()),
+            (status, _) => return Err(Error::BadContent(status.as_u16())),
+        };
+
+        if attempt >= self.max_retries {
+            return Err(error);
+        }
+
+        match retry_after(headers) {
+            Some(delay) if delay > self.max_retry_after => Err(Error::RateLimited),
+            Some(delay) => Ok(delay),
+            None => Ok(self.backoff * 2u32.pow(attempt)),
+        }
+    }
+
+    /// Sends the request, retrying it after 429 and 5xx responses, returns the body.
+    ///
+    /// Every attempt waits for its slot of the `limiter` first.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn send_blocking(
+        &self,
+        request: impl Fn() -> reqwest::blocking::RequestBuilder,
+        number: Option<&RegistrationNumber>,
+        limiter: Option<&RateLimiter>,
+    ) -> Result<String, Error> {
+        let mut attempt = 0;
+
+        loop {
+            if let Some(limiter) = limiter {
+                std::thread::sleep(limiter.reserve());
+            }
+
+            let response = request()
+                .send()
+                .map_err(Error::TransportError)?;
+
+            let status = response.status();
+
+            if status.is_success() {
+                return response
+                    .text()
+                    .map_err(Error::TransportError);
+            }
+
+            std::thread::sleep(
+                self.delay(
+                    number,
+                    status,
+                    response.headers(),
+                    attempt,
+                )?,
+            );
+
+            attempt += 1;
+        }
+    }
+
+    /// Sends the request, retrying it after 429 and 5xx responses, returns the body.
+    ///
+    /// Every attempt waits for its slot of the `limiter` first.
+    #[cfg(feature = "async")]
+    pub(crate) async fn send_async(
+        &self,
+        request: impl Fn() -> reqwest::RequestBuilder,
+        number: Option<&RegistrationNumber>,
+        limiter: Option<&RateLimiter>,
+    ) -> Result<String, Error> {
+        let mut attempt = 0;
+
+        loop {
+            if let Some(limiter) = limiter {
+                tokio::time::sleep(limiter.reserve()).await;
+            }
+
+            let response = request()
+                .send()
+                .await
+                .map_err(Error::TransportError)?;
+
+            let status = response.status();
+
+            if status.is_success() {
+                return response
+                    .text()
+                    .await
+                    .map_err(Error::TransportError);
+            }
+
+            tokio::time::sleep(
+                self.delay(
+                    number,
+                    status,
+                    response.headers(),
+                    attempt,
+                )?,
+            )
+            .await;
+
+            attempt += 1;
+        }
+    }
+}
+
+/// Spaces the requests of concurrent workers of a bulk lookup, retries included
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    interval: Duration,
+    next: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            next: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Reserves the next free slot, returns how long to wait before sending the request
+    pub(crate) fn reserve(&self) -> Duration {
+        let mut next = self
+            .next
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+
+        let now = Instant::now();
+        let slot = (*next).max(now);
+
+        *next = slot + self.interval;
+
+        slot - now
+    }
+}
+
+/// Reads the delay requested by the server in the `Retry-After` header
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Joins the base URL and the path of the endpoint
+pub(crate) fn url(
+    base_url: &str,
+    path: &str,
+) -> String {
+    format!(
+        "{}/{}",
+        base_url.trim_end_matches('/'),
+        path
+    )
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::io::Read;
+    use std::io::Write;
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::*;
+
+    /// Serves the given responses to consecutive requests, returns the base URL
+    pub(crate) fn serve(
+        responses: Vec<(
+            u16,
+            &'static str,
+        )>
+    ) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener
+            .local_addr()
+            .unwrap();
+
+        thread::spawn(
+            move || {
+                for (status, body) in responses {
+                    let (mut stream, _) = listener
+                        .accept()
+                        .unwrap();
+
+                    let mut buffer = [0; 4096];
+                    let _ = stream.read(&mut buffer);
+
+                    let _ = write!(
+                        stream,
+                        "HTTP/1.1 {} Status\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                }
+            },
+        );
+
+        format!(
+            "http://{}",
+            address
+        )
+    }
+
+    #[test]
+    fn test_rate_limiter() {
+        let limiter = RateLimiter::new(Duration::from_secs(10));
+
+        assert_eq!(
+            limiter.reserve(),
+            Duration::ZERO
+        );
+        assert!(limiter.reserve() > Duration::from_secs(9));
+        assert!(limiter.reserve() > Duration::from_secs(19));
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_limited_retry() {
+        let retry = Retry {
+            max_retries: 1,
+            backoff: Duration::from_millis(1),
+            max_retry_after: Duration::from_secs(60),
+        };
+        let limiter = RateLimiter::new(Duration::from_millis(300));
+        let url = serve(
+            vec![
+                (
+                    503, "",
+                ),
+                (
+                    200, "{}",
+                ),
+            ],
+        );
+
+        let start = Instant::now();
+        let body = retry.send_blocking(
+            || reqwest::blocking::Client::new().get(&url),
+            None,
+            Some(&limiter),
+        );
+
+        assert_eq!(
+            body.unwrap(),
+            "{}"
+        );
+        // The retry waited for the second slot of the limiter
+        assert!(start.elapsed() >= Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_retry() {
+        let config = Retry {
+            max_retries: 1,
+            backoff: Duration::from_millis(100),
+            max_retry_after: Duration::from_secs(60),
+        };
+        let number: RegistrationNumber = "25596641"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            config
+                .delay(
+                    Some(&number),
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    &HeaderMap::new(),
+                    0,
+                )
+                .unwrap(),
+            Duration::from_millis(100)
+        );
+        assert!(
+            matches!(
+                config.delay(
+                    None,
+                    StatusCode::TOO_MANY_REQUESTS,
+                    &HeaderMap::new(),
+                    1,
+                ),
+                Err(Error::RateLimited)
+            )
+        );
+        assert!(
+            matches!(
+                config.delay(
+                    Some(&number),
+                    StatusCode::NOT_FOUND,
+                    &HeaderMap::new(),
+                    0,
+                ),
+                Err(Error::NotFound(_))
+            )
+        );
+
+        let mut headers = HeaderMap::new();
+
+        headers.insert(
+            RETRY_AFTER,
+            "30".parse()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            config
+                .delay(
+                    None,
+                    StatusCode::TOO_MANY_REQUESTS,
+                    &headers,
+                    0,
+                )
+                .unwrap(),
+            Duration::from_secs(30)
+        );
+
+        headers.insert(
+            RETRY_AFTER,
+            "3600"
+                .parse()
+                .unwrap(),
+        );
+
+        assert!(
+            matches!(
+                config.delay(
+                    None,
+                    StatusCode::TOO_MANY_REQUESTS,
+                    &headers,
+                    0,
+                ),
+                Err(Error::RateLimited)
+            )
+        );
+    }
+}
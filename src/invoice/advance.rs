@@ -0,0 +1,174 @@
+use chrono::NaiveDate;
+use iban::Iban;
+use iso_currency::Currency;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Invoice;
+use super::InvoiceItem;
+use super::InvoiceKind;
+use crate::entity::Entity;
+use crate::payment_method::PaymentMethod;
+
+/// Advance paid by the client and deducted on the final invoice (uhrazená záloha).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Advance {
+    /// Number of the advance invoice
+    pub number: Decimal,
+    pub amount: Decimal,
+}
+
+impl Advance {
+    pub fn new(
+        number: Decimal,
+        amount: Decimal,
+    ) -> Self {
+        Self {
+            number,
+            amount,
+        }
+    }
+}
+
+impl From<&Invoice> for Advance {
+    fn from(invoice: &Invoice) -> Self {
+        Self::new(
+            invoice.number,
+            invoice.balance(),
+        )
+    }
+}
+
+impl Invoice {
+    /// Creates an advance invoice (zálohová faktura).
+    ///
+    /// An advance invoice is only a request for payment, it isn't a tax document.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_advance(
+        number: Decimal,
+        contractor: impl Into<Entity>,
+        client: impl Into<Entity>,
+        iban: Option<Iban>,
+        payment_method: PaymentMethod,
+        items: Vec<InvoiceItem>,
+        date: NaiveDate,
+        due_date: NaiveDate,
+        currency: Currency,
+        note: Option<impl Into<String>>,
+    ) -> Self {
+        let mut invoice = Self::new(
+            number,
+            contractor,
+            client,
+            iban,
+            payment_method,
+            items,
+            date,
+            due_date,
+            currency,
+            note,
+        );
+
+        invoice.kind = InvoiceKind::Advance;
+        invoice
+    }
+
+    /// Deducts a received advance from the amount to be paid.
+    pub fn add_advance(
+        &mut self,
+        advance: Advance,
+    ) {
+        self.advances
+            .push(advance);
+    }
+
+    pub fn advances(&self) -> &[Advance] {
+        &self.advances
+    }
+
+    /// Returns the amount left to be paid after deducting the advances.
+    pub fn balance(&self) -> Decimal {
+        self.total()
+            - self
+                .advances
+                .iter()
+                .map(|x| x.amount)
+                .sum::<Decimal>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::date;
+    use crate::fixtures::entity;
+    use crate::fixtures::iban;
+    use crate::fixtures::invoice;
+    use crate::fixtures::items;
+
+    #[test]
+    fn test_advance_settlement() {
+        let advance = Invoice::new_advance(
+            Decimal::new(
+                202400, 0,
+            ),
+            entity("27082440"),
+            entity("25596641"),
+            Some(iban()),
+            PaymentMethod::BankTransfer("202400".to_string()),
+            items(300),
+            date(),
+            date(),
+            Currency::CZK,
+            None::<&str>,
+        );
+
+        assert_eq!(
+            advance.title(),
+            "Zálohová faktura"
+        );
+
+        let mut invoice = invoice(
+            entity("27082440"),
+            entity("25596641"),
+            Some(iban()),
+            Currency::CZK,
+        );
+        invoice.add_advance((&advance).into());
+
+        assert_eq!(
+            invoice.total(),
+            Decimal::new(1000, 0)
+        );
+        assert_eq!(
+            invoice.balance(),
+            Decimal::new(700, 0)
+        );
+        assert!(
+            invoice
+                .spayd(&invoice.balance())
+                .unwrap()
+                .unwrap()
+                .contains("AM:700")
+        );
+
+        invoice.add_advance(
+            Advance::new(
+                Decimal::new(
+                    202402, 0,
+                ),
+                Decimal::new(
+                    700, 0,
+                ),
+            ),
+        );
+
+        assert!(
+            invoice
+                .spayd(&invoice.balance())
+                .unwrap()
+                .is_none()
+        );
+    }
+}
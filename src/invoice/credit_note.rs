@@ -0,0 +1,216 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Invoice;
+use super::InvoiceItem;
+use super::InvoiceKind;
+
+/// Reference of a credit note (opravný daňový doklad) to the corrected invoice.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Correction {
+    pub original_number: Decimal,
+    pub original_date: NaiveDate,
+    pub reason: String,
+}
+
+impl Correction {
+    pub fn new(
+        original_number: Decimal,
+        original_date: NaiveDate,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            original_number,
+            original_date,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl Invoice {
+    /// Creates a credit note correcting this invoice.
+    ///
+    /// The items hold the differences, negative prices lower the invoiced amount.
+    pub fn new_credit_note(
+        &self,
+        number: Decimal,
+        reason: impl Into<String>,
+        items: Vec<InvoiceItem>,
+        date: NaiveDate,
+        due_date: NaiveDate,
+    ) -> Self {
+        Self {
+            number,
+            kind: InvoiceKind::CreditNote(
+                Correction::new(
+                    self.number,
+                    self.date,
+                    reason,
+                ),
+            ),
+            contractor: self
+                .contractor
+                .clone(),
+            client: self
+                .client
+                .clone(),
+            iban: self.iban,
+            bic: self
+                .bic
+                .clone(),
+            payment_method: self
+                .payment_method
+                .clone(),
+            items,
+            advances: Vec::new(),
+            date,
+            due_date,
+            currency: self.currency,
+            note: None,
+            consultation_number: None,
+            constant_symbol: self
+                .constant_symbol
+                .clone(),
+            specific_symbol: self
+                .specific_symbol
+                .clone(),
+        }
+    }
+
+    /// Creates a credit note cancelling the whole invoice.
+    pub fn cancel(
+        &self,
+        number: Decimal,
+        reason: impl Into<String>,
+        date: NaiveDate,
+    ) -> Self {
+        self.new_credit_note(
+            number,
+            reason,
+            self.items
+                .iter()
+                .map(InvoiceItem::negated)
+                .collect(),
+            date,
+            date,
+        )
+    }
+
+    /// Returns the correction if the invoice is a credit note.
+    pub fn correction(&self) -> Option<&Correction> {
+        match &self.kind {
+            InvoiceKind::CreditNote(correction) => Some(correction),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iso_currency::Currency;
+
+    use super::*;
+    use crate::entity::eu;
+    use crate::fixtures::date;
+    use crate::fixtures::entity;
+    use crate::fixtures::iban;
+    use crate::fixtures::invoice;
+
+    #[test]
+    fn test_cancel() {
+        let invoice = invoice(
+            entity("27082440"),
+            entity("25596641"),
+            Some(iban()),
+            Currency::CZK,
+        );
+        let date = date() + chrono::Duration::days(7);
+
+        let credit_note = invoice.cancel(
+            Decimal::new(
+                202402, 0,
+            ),
+            "Vrácení zboží",
+            date,
+        );
+
+        assert_eq!(
+            credit_note.title(),
+            "Opravná faktura"
+        );
+        assert_eq!(
+            credit_note.correction(),
+            Some(
+                &Correction::new(
+                    invoice.number,
+                    invoice.date,
+                    "Vrácení zboží",
+                ),
+            )
+        );
+        assert_eq!(
+            credit_note.date,
+            date
+        );
+        assert_eq!(
+            credit_note
+                .items
+                .iter()
+                .map(InvoiceItem::price)
+                .collect::<Vec<_>>(),
+            invoice
+                .items
+                .iter()
+                .map(|x| -x.price())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            credit_note.total(),
+            -invoice.total()
+        );
+        assert_eq!(
+            credit_note
+                .spayd(&credit_note.total())
+                .unwrap(),
+            None
+        );
+        assert!(
+            invoice
+                .correction()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_title_of_vat_payer() {
+        let contractor = eu::Entity {
+            vat_number: Some(
+                "CZ27082440"
+                    .parse()
+                    .unwrap(),
+            ),
+            ..entity("27082440")
+        };
+
+        let credit_note = invoice(
+            contractor,
+            entity("25596641"),
+            Some(iban()),
+            Currency::CZK,
+        )
+        .cancel(
+            Decimal::new(
+                202402, 0,
+            ),
+            "Sleva",
+            date(),
+        );
+
+        assert_eq!(
+            credit_note.title(),
+            "Opravný daňový doklad"
+        );
+    }
+}
@@ -0,0 +1,1802 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::NaiveDate;
+use iban::Iban;
+use iban::IbanLike;
+use iso_currency::Currency;
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use super::advance::Advance;
+use super::credit_note::Correction;
+use super::Invoice;
+use super::InvoiceItem;
+use super::InvoiceItemType;
+use super::InvoiceKind;
+use crate::address::Address;
+use crate::bank_account::CzBankAccount;
+use crate::country::CountryCode;
+use crate::entity::eu;
+use crate::entity::us;
+use crate::entity::Entity;
+use crate::payment_method::PaymentMethod;
+use crate::registration_number::RegistrationNumber;
+use crate::time::Time;
+use crate::vat::PriceType;
+use crate::vat::VatRate;
+use crate::vat::VatSummary;
+
+const NAMESPACE: &str = "http://isdoc.cz/namespace/2013";
+const VERSION: &str = "6.0.2";
+
+/// ISDOC document type of a regular invoice
+const DOCUMENT_TYPE_INVOICE: u32 = 1;
+/// ISDOC document type of a credit note
+const DOCUMENT_TYPE_CREDIT_NOTE: u32 = 2;
+/// ISDOC document type of a correction raising the invoiced amount
+const DOCUMENT_TYPE_DEBIT_NOTE: u32 = 3;
+/// ISDOC document type of an advance invoice, which isn't a tax document
+const DOCUMENT_TYPE_ADVANCE: u32 = 4;
+
+const PAYMENT_MEANS_CASH: u32 = 10;
+const PAYMENT_MEANS_BANK_TRANSFER: u32 = 42;
+const PAYMENT_MEANS_CARD: u32 = 48;
+
+const UNIT_HOURS: &str = "h";
+const UNIT_HOURS_UNECE: &str = "HUR";
+const UNIT_PIECES: &str = "ks";
+
+/// The reason of a credit note and the note share the ISDOC `Note`, the reason is on the first line.
+/// Line breaks and backslashes of the reason are escaped, so it can't contain the separator.
+const NOTE_SEPARATOR: char = '\n';
+
+const VAT_CALCULATION_FROM_BELOW: u32 = 0;
+const VAT_CALCULATION_FROM_ABOVE: u32 = 1;
+
+fn one() -> Decimal {
+    Decimal::ONE
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(
+    rename = "Invoice",
+    rename_all = "PascalCase"
+)]
+struct IsdocInvoice {
+    #[serde(
+        rename = "@xmlns",
+        default
+    )]
+    xmlns: String,
+
+    #[serde(
+        rename = "@version",
+        default
+    )]
+    version: String,
+
+    document_type: u32,
+
+    #[serde(rename = "ID")]
+    id: String,
+
+    #[serde(rename = "UUID")]
+    uuid: String,
+
+    issue_date: NaiveDate,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_point_date: Option<NaiveDate>,
+
+    #[serde(rename = "VATApplicable")]
+    vat_applicable: bool,
+
+    #[serde(default)]
+    electronic_possibility_agreement_reference: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+
+    local_currency_code: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    foreign_currency_code: Option<String>,
+
+    #[serde(
+        default = "one",
+        with = "rust_decimal::serde::str"
+    )]
+    curr_rate: Decimal,
+
+    #[serde(
+        default = "one",
+        with = "rust_decimal::serde::str"
+    )]
+    ref_curr_rate: Decimal,
+
+    accounting_supplier_party: IsdocPartyWrapper,
+
+    accounting_customer_party: IsdocPartyWrapper,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    original_document_references: Option<IsdocOriginalDocumentReferences>,
+
+    invoice_lines: IsdocInvoiceLines,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    non_taxed_deposits: Option<IsdocNonTaxedDeposits>,
+
+    tax_total: IsdocTaxTotal,
+
+    legal_monetary_total: IsdocLegalMonetaryTotal,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payment_means: Option<IsdocPaymentMeans>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocPartyWrapper {
+    party: IsdocParty,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocParty {
+    party_identification: IsdocPartyIdentification,
+
+    party_name: IsdocPartyName,
+
+    postal_address: IsdocPostalAddress,
+
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    party_tax_scheme: Vec<IsdocPartyTaxScheme>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IsdocPartyIdentification {
+    #[serde(rename = "ID")]
+    id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocPartyName {
+    name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocPostalAddress {
+    #[serde(default)]
+    street_name: String,
+
+    #[serde(default)]
+    building_number: String,
+
+    #[serde(default)]
+    city_name: String,
+
+    #[serde(default)]
+    postal_zone: String,
+
+    country: IsdocCountry,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocCountry {
+    identification_code: String,
+
+    #[serde(default)]
+    name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocPartyTaxScheme {
+    #[serde(rename = "CompanyID")]
+    company_id: String,
+
+    tax_scheme: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IsdocOriginalDocumentReferences {
+    #[serde(
+        rename = "OriginalDocumentReference",
+        default
+    )]
+    references: Vec<IsdocOriginalDocumentReference>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocOriginalDocumentReference {
+    #[serde(
+        rename = "@id",
+        default
+    )]
+    reference_id: String,
+
+    #[serde(rename = "ID")]
+    id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issue_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IsdocNonTaxedDeposits {
+    #[serde(
+        rename = "NonTaxedDeposit",
+        default
+    )]
+    deposits: Vec<IsdocNonTaxedDeposit>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocNonTaxedDeposit {
+    #[serde(rename = "ID")]
+    id: String,
+
+    variable_symbol: String,
+
+    #[serde(with = "rust_decimal::serde::str")]
+    deposit_amount: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IsdocInvoiceLines {
+    #[serde(
+        rename = "InvoiceLine",
+        default
+    )]
+    lines: Vec<IsdocInvoiceLine>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocInvoiceLine {
+    #[serde(rename = "ID")]
+    id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invoiced_quantity: Option<IsdocQuantity>,
+
+    #[serde(with = "rust_decimal::serde::str")]
+    line_extension_amount: Decimal,
+
+    #[serde(with = "rust_decimal::serde::str")]
+    line_extension_amount_tax_inclusive: Decimal,
+
+    #[serde(with = "rust_decimal::serde::str")]
+    line_extension_tax_amount: Decimal,
+
+    #[serde(with = "rust_decimal::serde::str")]
+    unit_price: Decimal,
+
+    #[serde(with = "rust_decimal::serde::str")]
+    unit_price_tax_inclusive: Decimal,
+
+    classified_tax_category: IsdocClassifiedTaxCategory,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    item: Option<IsdocItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IsdocQuantity {
+    #[serde(
+        rename = "@unitCode",
+        skip_serializing_if = "Option::is_none"
+    )]
+    unit_code: Option<String>,
+
+    #[serde(
+        rename = "$text",
+        with = "rust_decimal::serde::str"
+    )]
+    value: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocClassifiedTaxCategory {
+    #[serde(with = "rust_decimal::serde::str")]
+    percent: Decimal,
+
+    #[serde(rename = "VATCalculationMethod")]
+    vat_calculation_method: u32,
+
+    #[serde(
+        rename = "VATApplicable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    vat_applicable: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocTaxTotal {
+    #[serde(
+        rename = "TaxSubTotal",
+        default
+    )]
+    sub_totals: Vec<IsdocTaxSubTotal>,
+
+    #[serde(with = "rust_decimal::serde::str")]
+    tax_amount: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocTaxSubTotal {
+    #[serde(with = "rust_decimal::serde::str")]
+    taxable_amount: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    tax_amount: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    tax_inclusive_amount: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    already_claimed_taxable_amount: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    already_claimed_tax_amount: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    already_claimed_tax_inclusive_amount: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    difference_taxable_amount: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    difference_tax_amount: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    difference_tax_inclusive_amount: Decimal,
+    tax_category: IsdocTaxCategory,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocTaxCategory {
+    #[serde(with = "rust_decimal::serde::str")]
+    percent: Decimal,
+
+    #[serde(
+        rename = "VATApplicable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    vat_applicable: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocLegalMonetaryTotal {
+    #[serde(with = "rust_decimal::serde::str")]
+    tax_exclusive_amount: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    tax_inclusive_amount: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    already_claimed_tax_exclusive_amount: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    already_claimed_tax_inclusive_amount: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    difference_tax_exclusive_amount: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    difference_tax_inclusive_amount: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    paid_deposits_amount: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    payable_amount: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocPaymentMeans {
+    payment: IsdocPayment,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocPayment {
+    #[serde(with = "rust_decimal::serde::str")]
+    paid_amount: Decimal,
+
+    payment_means_code: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<IsdocPaymentDetails>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IsdocPaymentDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payment_due_date: Option<NaiveDate>,
+
+    #[serde(
+        rename = "ID",
+        default
+    )]
+    id: String,
+
+    #[serde(default)]
+    bank_code: String,
+
+    #[serde(default)]
+    name: String,
+
+    #[serde(
+        rename = "IBAN",
+        skip_serializing_if = "Option::is_none"
+    )]
+    iban: Option<String>,
+
+    #[serde(
+        rename = "BIC",
+        default
+    )]
+    bic: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variable_symbol: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    constant_symbol: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    specific_symbol: Option<String>,
+}
+
+/// Errors that can occur during ISDOC export or import
+#[derive(Debug)]
+pub enum Error {
+    XmlError(quick_xml::DeError),
+    MissingField(&'static str),
+    InvalidValue(&'static str),
+    /// VAT rate in percent that has no [`VatRate`]
+    UnsupportedVatRate(Decimal),
+}
+
+impl Display for Error {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Error::XmlError(e) => {
+                write!(
+                    f,
+                    "XML error: {}",
+                    e
+                )
+            }
+            Error::MissingField(field) => {
+                write!(
+                    f,
+                    "Missing field: {}",
+                    field
+                )
+            }
+            Error::InvalidValue(field) => {
+                write!(
+                    f,
+                    "Invalid value of field: {}",
+                    field
+                )
+            }
+            Error::UnsupportedVatRate(percent) => {
+                write!(
+                    f,
+                    "Unsupported VAT rate: {} %",
+                    percent
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<&Entity> for IsdocPartyWrapper {
+    fn from(entity: &Entity) -> Self {
+        match entity {
+            Entity::Eu(entity) => entity.into(),
+            Entity::Us(entity) => entity.into(),
+        }
+    }
+}
+
+impl From<&eu::Entity> for IsdocPartyWrapper {
+    fn from(entity: &eu::Entity) -> Self {
+        let country = entity
+            .address
+            .country;
+
+        Self {
+            party: IsdocParty {
+                party_identification: IsdocPartyIdentification {
+                    id: entity
+                        .identifier
+                        .to_string(),
+                },
+                party_name: IsdocPartyName {
+                    name: entity
+                        .name
+                        .clone(),
+                },
+                postal_address: IsdocPostalAddress {
+                    street_name: entity
+                        .address
+                        .street
+                        .clone(),
+                    building_number: entity
+                        .address
+                        .house_number
+                        .clone(),
+                    city_name: entity
+                        .address
+                        .city
+                        .clone(),
+                    postal_zone: entity
+                        .address
+                        .postal_code
+                        .clone(),
+                    country: IsdocCountry {
+                        identification_code: country
+                            .code()
+                            .to_string(),
+                        name: country
+                            .name()
+                            .to_string(),
+                    },
+                },
+                party_tax_scheme: entity
+                    .vat_number
+                    .iter()
+                    .map(
+                        |vat_number| {
+                            IsdocPartyTaxScheme {
+                                company_id: vat_number.to_string(),
+                                tax_scheme: "VAT".to_string(),
+                            }
+                        },
+                    )
+                    .collect(),
+            },
+        }
+    }
+}
+
+/// ISDOC has no field for the state, it's stored in the city, e.g. `Mountain View, CA`
+impl From<&us::Entity> for IsdocPartyWrapper {
+    fn from(entity: &us::Entity) -> Self {
+        Self {
+            party: IsdocParty {
+                party_identification: IsdocPartyIdentification {
+                    id: entity
+                        .tax_id
+                        .to_string(),
+                },
+                party_name: IsdocPartyName {
+                    name: entity
+                        .name
+                        .clone(),
+                },
+                postal_address: IsdocPostalAddress {
+                    street_name: entity
+                        .address
+                        .street
+                        .clone(),
+                    building_number: String::new(),
+                    city_name: format!(
+                        "{}, {}",
+                        entity
+                            .address
+                            .city,
+                        entity
+                            .address
+                            .state()
+                    ),
+                    postal_zone: entity
+                        .address
+                        .zip
+                        .to_string(),
+                    country: IsdocCountry {
+                        identification_code: "US".to_string(),
+                        name: "United States".to_string(),
+                    },
+                },
+                party_tax_scheme: Vec::new(),
+            },
+        }
+    }
+}
+
+impl TryFrom<IsdocPartyWrapper> for Entity {
+    type Error = Error;
+
+    fn try_from(value: IsdocPartyWrapper) -> Result<Self, Self::Error> {
+        match value
+            .party
+            .postal_address
+            .country
+            .identification_code
+            .trim()
+        {
+            "US" => us::Entity::try_from(value).map(Entity::Us),
+            _ => eu::Entity::try_from(value).map(Entity::Eu),
+        }
+    }
+}
+
+impl TryFrom<IsdocPartyWrapper> for us::Entity {
+    type Error = Error;
+
+    fn try_from(value: IsdocPartyWrapper) -> Result<Self, Self::Error> {
+        let party = value.party;
+        let address = party.postal_address;
+
+        let id = party
+            .party_identification
+            .id
+            .trim();
+
+        let tax_id = match id.parse() {
+            Ok(ein) => us::Vat::Ein(ein),
+            Err(_) => {
+                us::Vat::Tin(
+                    id.parse()
+                        .or(Err(Error::InvalidValue("PartyIdentification")))?,
+                )
+            }
+        };
+
+        let (city, state) = address
+            .city_name
+            .rsplit_once(',')
+            .ok_or(Error::InvalidValue("CityName"))?;
+
+        let street = match address
+            .building_number
+            .trim()
+        {
+            "" => address.street_name,
+            building_number => {
+                format!(
+                    "{} {}",
+                    building_number, address.street_name
+                )
+            }
+        };
+
+        Ok(
+            us::Entity::new(
+                party
+                    .party_name
+                    .name,
+                us::Address::new(
+                    street,
+                    city.trim(),
+                    state,
+                    address
+                        .postal_zone
+                        .parse()
+                        .or(Err(Error::InvalidValue("PostalZone")))?,
+                )
+                .or(Err(Error::InvalidValue("CityName")))?,
+                tax_id,
+            ),
+        )
+    }
+}
+
+impl TryFrom<IsdocPartyWrapper> for eu::Entity {
+    type Error = Error;
+
+    fn try_from(value: IsdocPartyWrapper) -> Result<Self, Self::Error> {
+        let party = value.party;
+        let address = party.postal_address;
+
+        let country: CountryCode = address
+            .country
+            .identification_code
+            .trim()
+            .parse()
+            .or(Err(Error::InvalidValue("IdentificationCode")))?;
+
+        let vat_number = party
+            .party_tax_scheme
+            .into_iter()
+            .find(|x| x.tax_scheme == "VAT")
+            .map(
+                |x| {
+                    x.company_id
+                        .parse()
+                },
+            )
+            .transpose()
+            .or(Err(Error::InvalidValue("PartyTaxScheme")))?;
+
+        Ok(
+            eu::Entity::new(
+                RegistrationNumber::new(
+                    country,
+                    party
+                        .party_identification
+                        .id
+                        .trim(),
+                )
+                .or(Err(Error::InvalidValue("PartyIdentification")))?,
+                party
+                    .party_name
+                    .name,
+                Address::new(
+                    country,
+                    address.city_name,
+                    address.street_name,
+                    address
+                        .postal_zone
+                        .replace(
+                            ' ', "",
+                        ),
+                    address
+                        .building_number
+                        .trim(),
+                ),
+                vat_number,
+            ),
+        )
+    }
+}
+
+/// Converts ISDOC percent and VAT applicability into a VAT rate, only the Czech rates are supported
+fn vat_rate(category: &IsdocClassifiedTaxCategory) -> Result<VatRate, Error> {
+    if category.vat_applicable == Some(false) {
+        return Ok(VatRate::Exempt);
+    }
+
+    [
+        VatRate::Standard,
+        VatRate::Reduced,
+        VatRate::Zero,
+    ]
+    .into_iter()
+    .find(|rate| rate.percentage() == category.percent)
+    .ok_or(Error::UnsupportedVatRate(category.percent))
+}
+
+/// Escapes line breaks and backslashes of the credit note reason
+fn escape_reason(reason: &str) -> String {
+    reason
+        .replace(
+            '\\', "\\\\",
+        )
+        .replace(
+            NOTE_SEPARATOR,
+            "\\n",
+        )
+}
+
+/// Reverts [`escape_reason`]
+fn unescape_reason(reason: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = reason.chars();
+
+    while let Some(c) = chars.next() {
+        match (
+            c,
+            chars
+                .clone()
+                .next(),
+        ) {
+            ('\\', Some('n')) => {
+                unescaped.push(NOTE_SEPARATOR);
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                unescaped.push('\\');
+                chars.next();
+            }
+            (c, _) => unescaped.push(c),
+        }
+    }
+
+    unescaped
+}
+
+/// Reads the number of a document.
+///
+/// Invoice numbers are numeric, identifiers such as `FV-2024/001` give the number made of their digits.
+fn document_number(
+    id: &str,
+    field: &'static str,
+) -> Result<Decimal, Error> {
+    let id = id.trim();
+
+    if let Ok(number) = id.parse() {
+        return Ok(number);
+    }
+
+    id.chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>()
+        .parse()
+        .or(Err(Error::InvalidValue(field)))
+}
+
+impl TryFrom<IsdocInvoiceLine> for InvoiceItem {
+    type Error = Error;
+
+    fn try_from(line: IsdocInvoiceLine) -> Result<Self, Self::Error> {
+        let vat_rate = vat_rate(&line.classified_tax_category)?;
+
+        let (price_type, price_per_unit, line_price) = match line
+            .classified_tax_category
+            .vat_calculation_method
+        {
+            VAT_CALCULATION_FROM_ABOVE => {
+                (
+                    PriceType::WithVat,
+                    line.unit_price_tax_inclusive,
+                    line.line_extension_amount_tax_inclusive,
+                )
+            }
+            _ => {
+                (
+                    PriceType::WithoutVat,
+                    line.unit_price,
+                    line.line_extension_amount,
+                )
+            }
+        };
+
+        let (item_type, price_per_unit) = match line.invoiced_quantity {
+            Some(quantity)
+                if matches!(
+                    quantity
+                        .unit_code
+                        .as_deref(),
+                    Some(UNIT_HOURS | UNIT_HOURS_UNECE)
+                ) =>
+            {
+                let hours = quantity
+                    .value
+                    .trunc();
+                let minutes = ((quantity.value - hours) * Decimal::from(60)).round();
+
+                (
+                    InvoiceItemType::Hours(
+                        Time::new(
+                            hours
+                                .to_u32()
+                                .ok_or(Error::InvalidValue("InvoicedQuantity"))?,
+                            minutes
+                                .to_u32()
+                                .ok_or(Error::InvalidValue("InvoicedQuantity"))?,
+                        ),
+                    ),
+                    price_per_unit,
+                )
+            }
+            Some(quantity)
+                if quantity
+                    .value
+                    .fract()
+                    .is_zero() =>
+            {
+                (
+                    InvoiceItemType::Quantity(
+                        quantity
+                            .value
+                            .to_u32()
+                            .ok_or(Error::InvalidValue("InvoicedQuantity"))?,
+                    ),
+                    price_per_unit,
+                )
+            }
+            Some(quantity) => {
+                (
+                    InvoiceItemType::Other(
+                        format!(
+                            "{} {}",
+                            quantity.value,
+                            quantity
+                                .unit_code
+                                .unwrap_or_default()
+                        )
+                        .trim()
+                        .to_string(),
+                    ),
+                    line_price,
+                )
+            }
+            None => {
+                (
+                    InvoiceItemType::Other(String::new()),
+                    line_price,
+                )
+            }
+        };
+
+        Ok(
+            InvoiceItem::new_with_vat(
+                item_type,
+                line.item
+                    .and_then(|x| x.description)
+                    .unwrap_or_default(),
+                price_per_unit,
+                vat_rate,
+                price_type,
+            ),
+        )
+    }
+}
+
+impl Invoice {
+    fn isdoc_decimals(&self) -> u32 {
+        self.currency
+            .exponent()
+            .unwrap_or(2) as u32
+    }
+
+    fn isdoc_line(
+        &self,
+        index: usize,
+        item: &InvoiceItem,
+    ) -> IsdocInvoiceLine {
+        let decimals = self.isdoc_decimals();
+        let vat_rate = self.vat_rate(item);
+
+        let summary = |price: Decimal| {
+            match item.price_type {
+                PriceType::WithoutVat => {
+                    VatSummary::new(
+                        vat_rate,
+                        price,
+                        Decimal::ZERO,
+                        decimals,
+                    )
+                }
+                PriceType::WithVat => {
+                    VatSummary::new(
+                        vat_rate,
+                        Decimal::ZERO,
+                        price,
+                        decimals,
+                    )
+                }
+            }
+        };
+
+        let line = summary(item.price());
+        let unit = summary(item.price_per_unit);
+
+        let invoiced_quantity = match &item.item_type {
+            InvoiceItemType::Hours(time) => {
+                Some(
+                    IsdocQuantity {
+                        unit_code: Some(UNIT_HOURS.to_string()),
+                        value: Decimal::from_f64(time.hour_multiplicator())
+                            .unwrap_or_default()
+                            .round_dp(4),
+                    },
+                )
+            }
+            InvoiceItemType::Quantity(quantity) => {
+                Some(
+                    IsdocQuantity {
+                        unit_code: Some(UNIT_PIECES.to_string()),
+                        value: Decimal::from(*quantity),
+                    },
+                )
+            }
+            InvoiceItemType::Other(_) => None,
+        };
+
+        IsdocInvoiceLine {
+            id: (index + 1).to_string(),
+            invoiced_quantity,
+            line_extension_amount: line.base,
+            line_extension_amount_tax_inclusive: line.total,
+            line_extension_tax_amount: line.vat,
+            unit_price: unit.base,
+            unit_price_tax_inclusive: unit.total,
+            classified_tax_category: IsdocClassifiedTaxCategory {
+                percent: vat_rate.percentage(),
+                vat_calculation_method: match item.price_type {
+                    PriceType::WithoutVat => VAT_CALCULATION_FROM_BELOW,
+                    PriceType::WithVat => VAT_CALCULATION_FROM_ABOVE,
+                },
+                vat_applicable: Some(vat_rate != VatRate::Exempt),
+            },
+            item: Some(
+                IsdocItem {
+                    description: Some(
+                        item.description
+                            .clone(),
+                    ),
+                },
+            ),
+        }
+    }
+
+    /// Exports the invoice as an ISDOC 6.0.2 document.
+    pub fn to_isdoc(&self) -> Result<String, Error> {
+        let vat_payer = self
+            .contractor
+            .is_vat_payer();
+
+        let summaries = if vat_payer {
+            self.vat_recapitulation()
+        } else {
+            vec![
+                VatSummary::new(
+                    VatRate::Exempt,
+                    self.total(),
+                    Decimal::ZERO,
+                    self.isdoc_decimals(),
+                ),
+            ]
+        };
+
+        let tax_exclusive_amount: Decimal = summaries
+            .iter()
+            .map(|x| x.base)
+            .sum();
+        let tax_amount: Decimal = summaries
+            .iter()
+            .map(|x| x.vat)
+            .sum();
+        let tax_inclusive_amount: Decimal = summaries
+            .iter()
+            .map(|x| x.total)
+            .sum();
+
+        let paid_deposits_amount: Decimal = self
+            .advances
+            .iter()
+            .map(|x| x.amount)
+            .sum();
+        let payable_amount = tax_inclusive_amount - paid_deposits_amount;
+
+        let uuid = Uuid::new_v5(
+            &Uuid::NAMESPACE_OID,
+            format!(
+                "isdoc:{}:{}",
+                self.contractor
+                    .identifier(),
+                self.number
+            )
+            .as_bytes(),
+        );
+
+        let bank_account = self
+            .iban()
+            .and_then(|x| CzBankAccount::try_from(x).ok());
+
+        let (payment_means_code, details) = match &self.payment_method {
+            PaymentMethod::Cash => {
+                (
+                    PAYMENT_MEANS_CASH,
+                    None,
+                )
+            }
+            PaymentMethod::Card(_) => {
+                (
+                    PAYMENT_MEANS_CARD,
+                    None,
+                )
+            }
+            PaymentMethod::BankTransfer(variable_symbol) => {
+                (
+                    PAYMENT_MEANS_BANK_TRANSFER,
+                    Some(
+                        IsdocPaymentDetails {
+                            payment_due_date: Some(self.due_date),
+                            id: bank_account
+                                .as_ref()
+                                .map(CzBankAccount::account_number)
+                                .unwrap_or_default(),
+                            bank_code: bank_account
+                                .as_ref()
+                                .map(
+                                    |x| {
+                                        x.bank_code()
+                                            .to_string()
+                                    },
+                                )
+                                .unwrap_or_default(),
+                            name: String::new(),
+                            iban: self
+                                .iban()
+                                .map(
+                                    |x| {
+                                        x.electronic_str()
+                                            .to_string()
+                                    },
+                                ),
+                            bic: self
+                                .bic()
+                                .unwrap_or_default(),
+                            variable_symbol: Some(variable_symbol.clone()),
+                            constant_symbol: self
+                                .constant_symbol
+                                .clone(),
+                            specific_symbol: self
+                                .specific_symbol
+                                .clone(),
+                        },
+                    ),
+                )
+            }
+        };
+
+        let document = IsdocInvoice {
+            xmlns: NAMESPACE.to_string(),
+            version: VERSION.to_string(),
+            document_type: match self.kind {
+                InvoiceKind::Invoice => DOCUMENT_TYPE_INVOICE,
+                InvoiceKind::CreditNote(_) if self.total() > Decimal::ZERO => {
+                    DOCUMENT_TYPE_DEBIT_NOTE
+                }
+                InvoiceKind::CreditNote(_) => DOCUMENT_TYPE_CREDIT_NOTE,
+                InvoiceKind::Advance => DOCUMENT_TYPE_ADVANCE,
+            },
+            id: self
+                .number
+                .to_string(),
+            uuid: uuid
+                .hyphenated()
+                .to_string()
+                .to_uppercase(),
+            issue_date: self.date,
+            tax_point_date: self
+                .is_tax_document()
+                .then_some(self.date),
+            vat_applicable: vat_payer,
+            electronic_possibility_agreement_reference: String::new(),
+            note: match (
+                self.correction(),
+                &self.note,
+            ) {
+                (Some(correction), Some(note)) => {
+                    Some(
+                        format!(
+                            "{}{}{}",
+                            escape_reason(&correction.reason),
+                            NOTE_SEPARATOR,
+                            note
+                        ),
+                    )
+                }
+                (Some(correction), None) => Some(escape_reason(&correction.reason)),
+                (None, note) => note.clone(),
+            },
+            local_currency_code: self
+                .currency
+                .code()
+                .to_string(),
+            foreign_currency_code: None,
+            curr_rate: Decimal::ONE,
+            ref_curr_rate: Decimal::ONE,
+            accounting_supplier_party: (&self.contractor).into(),
+            accounting_customer_party: (&self.client).into(),
+            original_document_references: self
+                .correction()
+                .map(
+                    |correction| {
+                        IsdocOriginalDocumentReferences {
+                            references: vec![
+                                IsdocOriginalDocumentReference {
+                                    reference_id: "original-1".to_string(),
+                                    id: correction
+                                        .original_number
+                                        .to_string(),
+                                    issue_date: Some(correction.original_date),
+                                },
+                            ],
+                        }
+                    },
+                ),
+            invoice_lines: IsdocInvoiceLines {
+                lines: self
+                    .items
+                    .iter()
+                    .enumerate()
+                    .map(
+                        |(index, item)| {
+                            self.isdoc_line(
+                                index, item,
+                            )
+                        },
+                    )
+                    .collect(),
+            },
+            non_taxed_deposits: (!self
+                .advances
+                .is_empty())
+            .then(
+                || {
+                    IsdocNonTaxedDeposits {
+                        deposits: self
+                            .advances
+                            .iter()
+                            .map(
+                                |advance| {
+                                    IsdocNonTaxedDeposit {
+                                        id: advance
+                                            .number
+                                            .to_string(),
+                                        variable_symbol: advance
+                                            .number
+                                            .to_string(),
+                                        deposit_amount: advance.amount,
+                                    }
+                                },
+                            )
+                            .collect(),
+                    }
+                },
+            ),
+            tax_total: IsdocTaxTotal {
+                sub_totals: summaries
+                    .iter()
+                    .map(
+                        |summary| {
+                            IsdocTaxSubTotal {
+                                taxable_amount: summary.base,
+                                tax_amount: summary.vat,
+                                tax_inclusive_amount: summary.total,
+                                already_claimed_taxable_amount: Decimal::ZERO,
+                                already_claimed_tax_amount: Decimal::ZERO,
+                                already_claimed_tax_inclusive_amount: Decimal::ZERO,
+                                difference_taxable_amount: summary.base,
+                                difference_tax_amount: summary.vat,
+                                difference_tax_inclusive_amount: summary.total,
+                                tax_category: IsdocTaxCategory {
+                                    percent: summary
+                                        .rate
+                                        .percentage(),
+                                    vat_applicable: Some(summary.rate != VatRate::Exempt),
+                                },
+                            }
+                        },
+                    )
+                    .collect(),
+                tax_amount,
+            },
+            legal_monetary_total: IsdocLegalMonetaryTotal {
+                tax_exclusive_amount,
+                tax_inclusive_amount,
+                already_claimed_tax_exclusive_amount: Decimal::ZERO,
+                already_claimed_tax_inclusive_amount: Decimal::ZERO,
+                difference_tax_exclusive_amount: tax_exclusive_amount,
+                difference_tax_inclusive_amount: tax_inclusive_amount,
+                paid_deposits_amount,
+                payable_amount,
+            },
+            payment_means: Some(
+                IsdocPaymentMeans {
+                    payment: IsdocPayment {
+                        paid_amount: payable_amount,
+                        payment_means_code,
+                        details,
+                    },
+                },
+            ),
+        };
+
+        let xml = quick_xml::se::to_string(&document).map_err(Error::XmlError)?;
+
+        Ok(
+            format!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{}",
+                xml
+            ),
+        )
+    }
+
+    /// Imports an invoice from an ISDOC document.
+    pub fn from_isdoc(xml: &str) -> Result<Self, Error> {
+        let document: IsdocInvoice = quick_xml::de::from_str(xml).map_err(Error::XmlError)?;
+
+        let payment = document
+            .payment_means
+            .map(|x| x.payment);
+
+        let details = payment
+            .as_ref()
+            .and_then(
+                |x| {
+                    x.details
+                        .as_ref()
+                },
+            );
+
+        let iban: Option<Iban> = match details {
+            Some(details) => {
+                match details
+                    .iban
+                    .as_deref()
+                {
+                    Some(iban) => {
+                        Some(
+                            iban.parse()
+                                .or(Err(Error::InvalidValue("IBAN")))?,
+                        )
+                    }
+                    // Domestic documents may give only the account number and the bank code
+                    None if !details
+                        .id
+                        .is_empty() =>
+                    {
+                        Some(
+                            CzBankAccount::new(
+                                CountryCode::Cz,
+                                &format!(
+                                    "{}/{}",
+                                    details.id, details.bank_code
+                                ),
+                            )
+                            .or(Err(Error::InvalidValue("ID")))?
+                            .to_iban(),
+                        )
+                    }
+                    None => None,
+                }
+            }
+            None => None,
+        };
+
+        let due_date = details
+            .and_then(|x| x.payment_due_date)
+            .unwrap_or(document.issue_date);
+
+        let payment_method = match payment
+            .as_ref()
+            .map(|x| x.payment_means_code)
+        {
+            Some(PAYMENT_MEANS_CASH) => PaymentMethod::Cash,
+            Some(PAYMENT_MEANS_CARD) => PaymentMethod::Card(String::new()),
+            _ => {
+                PaymentMethod::BankTransfer(
+                    details
+                        .and_then(
+                            |x| {
+                                x.variable_symbol
+                                    .clone()
+                            },
+                        )
+                        .unwrap_or_default(),
+                )
+            }
+        };
+
+        let currency = Currency::from_code(
+            document
+                .local_currency_code
+                .trim(),
+        )
+        .ok_or(Error::InvalidValue("LocalCurrencyCode"))?;
+
+        let items = document
+            .invoice_lines
+            .lines
+            .into_iter()
+            .map(InvoiceItem::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut invoice = Invoice::new(
+            document_number(
+                &document.id,
+                "ID",
+            )?,
+            Entity::try_from(document.accounting_supplier_party)?,
+            Entity::try_from(document.accounting_customer_party)?,
+            iban,
+            payment_method,
+            items,
+            document.issue_date,
+            due_date,
+            currency,
+            document.note,
+        );
+
+        if let Some(bic) = details
+            .map(
+                |x| {
+                    x.bic
+                        .trim()
+                },
+            )
+            .filter(|x| !x.is_empty())
+        {
+            invoice.bic = Some(bic.to_string());
+        }
+
+        if let Some(details) = details {
+            invoice.constant_symbol = details
+                .constant_symbol
+                .clone();
+            invoice.specific_symbol = details
+                .specific_symbol
+                .clone();
+        }
+
+        if matches!(
+            document.document_type,
+            DOCUMENT_TYPE_CREDIT_NOTE | DOCUMENT_TYPE_DEBIT_NOTE
+        ) {
+            let note = invoice
+                .note
+                .take()
+                .unwrap_or_default();
+
+            let (reason, note) = match note.split_once(NOTE_SEPARATOR) {
+                Some((reason, note)) => {
+                    (
+                        unescape_reason(reason),
+                        Some(note.to_string()),
+                    )
+                }
+                None => {
+                    (
+                        unescape_reason(&note),
+                        None,
+                    )
+                }
+            };
+
+            invoice.note = note;
+
+            let reference = document
+                .original_document_references
+                .and_then(
+                    |x| {
+                        x.references
+                            .into_iter()
+                            .next()
+                    },
+                )
+                .ok_or(Error::MissingField("OriginalDocumentReference"))?;
+
+            invoice.kind = InvoiceKind::CreditNote(
+                Correction::new(
+                    document_number(
+                        &reference.id,
+                        "OriginalDocumentReference",
+                    )?,
+                    reference
+                        .issue_date
+                        .ok_or(Error::MissingField("OriginalDocumentReference"))?,
+                    reason,
+                ),
+            );
+        }
+
+        if document.document_type == DOCUMENT_TYPE_ADVANCE {
+            invoice.kind = InvoiceKind::Advance;
+        }
+
+        for deposit in document
+            .non_taxed_deposits
+            .map(|x| x.deposits)
+            .unwrap_or_default()
+        {
+            invoice.add_advance(
+                Advance::new(
+                    document_number(
+                        &deposit.id,
+                        "NonTaxedDeposit",
+                    )?,
+                    deposit.deposit_amount,
+                ),
+            );
+        }
+
+        Ok(invoice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::date;
+    use crate::fixtures::entity;
+    use crate::fixtures::iban;
+
+    #[test]
+    fn test_isdoc_roundtrip() {
+        let date = date();
+
+        let mut invoice = Invoice::new(
+            Decimal::new(
+                202403, 0,
+            ),
+            eu::Entity {
+                vat_number: Some(
+                    "CZ27082440"
+                        .parse()
+                        .unwrap(),
+                ),
+                ..entity("27082440")
+            },
+            entity("25596641"),
+            Some(iban()),
+            PaymentMethod::BankTransfer("202403".to_string()),
+            vec![
+                InvoiceItem::new_with_vat(
+                    InvoiceItemType::Hours(
+                        Time::new(
+                            1, 30,
+                        ),
+                    ),
+                    "Programování",
+                    Decimal::new(
+                        1000, 0,
+                    ),
+                    VatRate::Standard,
+                    PriceType::WithoutVat,
+                ),
+                InvoiceItem::new_with_vat(
+                    InvoiceItemType::Quantity(2),
+                    "Kniha",
+                    Decimal::new(
+                        500, 0,
+                    ),
+                    VatRate::Reduced,
+                    PriceType::WithVat,
+                ),
+            ],
+            date,
+            date + chrono::Duration::days(14),
+            Currency::CZK,
+            Some("Poznámka"),
+        );
+        invoice.set_constant_symbol("0308");
+        invoice.set_specific_symbol("42");
+
+        let xml = invoice
+            .to_isdoc()
+            .unwrap();
+
+        assert!(xml.contains("<DocumentType>1</DocumentType>"));
+        assert!(xml.contains("<VariableSymbol>202403</VariableSymbol><ConstantSymbol>0308</ConstantSymbol><SpecificSymbol>42</SpecificSymbol>"));
+
+        let parsed = Invoice::from_isdoc(&xml).unwrap();
+
+        assert_eq!(
+            parsed.number,
+            invoice.number
+        );
+        assert_eq!(
+            parsed.contractor,
+            invoice.contractor
+        );
+        assert_eq!(
+            parsed.client,
+            invoice.client
+        );
+        assert_eq!(
+            parsed.iban,
+            invoice.iban
+        );
+        assert_eq!(
+            parsed.due_date,
+            invoice.due_date
+        );
+        assert_eq!(
+            parsed.constant_symbol(),
+            Some("0308")
+        );
+        assert_eq!(
+            parsed.specific_symbol(),
+            Some("42")
+        );
+        assert_eq!(
+            parsed.total(),
+            invoice.total()
+        );
+        assert_eq!(
+            parsed.vat_recapitulation(),
+            invoice.vat_recapitulation()
+        );
+
+        let parsed = Invoice::from_isdoc(
+            &xml.replace(
+                "<ID>202403</ID>",
+                "<ID>FV-2024/003</ID>",
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(
+            parsed.number,
+            Decimal::new(2024003, 0)
+        );
+
+        let mut credit_note = invoice.cancel(
+            Decimal::new(
+                202404, 0,
+            ),
+            "Vrácení zboží\nreklamace č. 12\\2024",
+            date + chrono::Duration::days(7),
+        );
+
+        let xml = credit_note
+            .to_isdoc()
+            .unwrap();
+
+        assert!(xml.contains("<DocumentType>2</DocumentType>"));
+
+        let parsed = Invoice::from_isdoc(&xml).unwrap();
+
+        assert_eq!(
+            parsed.correction(),
+            credit_note.correction()
+        );
+        assert_eq!(
+            parsed.total(),
+            -invoice.total()
+        );
+        assert_eq!(
+            parsed.note,
+            None
+        );
+
+        credit_note.note = Some("Zboží vráceno poškozené".to_string());
+
+        let parsed = Invoice::from_isdoc(
+            &credit_note
+                .to_isdoc()
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            parsed.correction(),
+            credit_note.correction()
+        );
+        assert_eq!(
+            parsed.note,
+            credit_note.note
+        );
+
+        let debit_note = invoice.new_credit_note(
+            Decimal::new(
+                202405, 0,
+            ),
+            "Doúčtování",
+            vec![
+                InvoiceItem::new_with_vat(
+                    InvoiceItemType::Quantity(1),
+                    "Kniha",
+                    Decimal::new(
+                        100, 0,
+                    ),
+                    VatRate::Reduced,
+                    PriceType::WithoutVat,
+                ),
+            ],
+            date,
+            date,
+        );
+
+        let xml = debit_note
+            .to_isdoc()
+            .unwrap();
+
+        assert!(xml.contains("<DocumentType>3</DocumentType>"));
+
+        let parsed = Invoice::from_isdoc(&xml).unwrap();
+
+        assert_eq!(
+            parsed.correction(),
+            debit_note.correction()
+        );
+        assert_eq!(
+            parsed.total(),
+            debit_note.total()
+        );
+    }
+
+    #[test]
+    fn test_vat_rate() {
+        let category = |percent: i64, vat_applicable: Option<bool>| {
+            IsdocClassifiedTaxCategory {
+                percent: Decimal::new(
+                    percent, 0,
+                ),
+                vat_calculation_method: VAT_CALCULATION_FROM_BELOW,
+                vat_applicable,
+            }
+        };
+
+        assert_eq!(
+            vat_rate(&category(21, None)).unwrap(),
+            VatRate::Standard
+        );
+        assert_eq!(
+            vat_rate(
+                &category(
+                    0,
+                    Some(false),
+                ),
+            )
+            .unwrap(),
+            VatRate::Exempt
+        );
+
+        for percent in [
+            15, 10, 20,
+        ] {
+            assert!(
+                matches!(
+                    vat_rate(&category(percent, Some(true))),
+                    Err(Error::UnsupportedVatRate(x)) if x == Decimal::new(percent, 0)
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_document_number() {
+        assert_eq!(
+            document_number(" 202401 ", "ID").unwrap(),
+            Decimal::new(202401, 0)
+        );
+        assert_eq!(
+            document_number(
+                "FV-2024/001",
+                "ID",
+            )
+            .unwrap(),
+            Decimal::new(2024001, 0)
+        );
+        assert!(document_number("FV", "ID").is_err());
+    }
+
+    #[test]
+    fn test_us_party_roundtrip() {
+        let entity = Entity::Us(
+            us::Entity::new(
+                "Google LLC",
+                us::Address::new(
+                    "1600 Amphitheatre Parkway",
+                    "Mountain View",
+                    "CA",
+                    "94043-1351"
+                        .parse()
+                        .unwrap(),
+                )
+                .unwrap(),
+                us::Vat::Ein(
+                    "77-0493581"
+                        .parse()
+                        .unwrap(),
+                ),
+            ),
+        );
+
+        let party: IsdocPartyWrapper = (&entity).into();
+
+        assert_eq!(
+            Entity::try_from(party).unwrap(),
+            entity
+        );
+    }
+}
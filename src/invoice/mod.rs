@@ -0,0 +1,1174 @@
+pub mod advance;
+pub mod credit_note;
+pub mod isdoc;
+pub mod payment_code;
+pub mod pdf;
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::accounting;
+use crate::bank_account::BankAccount;
+use crate::bank_account::CzBankAccount;
+use crate::bank_code::Bank;
+use crate::bank_code::BankRegistry;
+use crate::entity::Entity;
+use crate::invoice::advance::Advance;
+use crate::invoice::credit_note::Correction;
+use crate::payment_method::PaymentMethod;
+use crate::time::Time;
+use crate::vat::PriceType;
+use crate::vat::VatRate;
+use crate::vat::VatSummary;
+
+use chrono::NaiveDate;
+use iban::Iban;
+use iso_currency::Currency;
+use maud::html;
+use maud::PreEscaped;
+use maud::DOCTYPE;
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+const DATE_FORMAT: &str = "%d. %m. %Y";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum InvoiceItemType {
+    Hours(Time),
+    Quantity(u32),
+    Other(String),
+}
+
+impl FromStr for InvoiceItemType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if let Ok(time) = value.parse() {
+            return Ok(Self::Hours(time));
+        }
+
+        if let Ok(quantity) = value.parse() {
+            return Ok(Self::Quantity(quantity));
+        }
+
+        Ok(Self::Other(value.to_string()))
+    }
+}
+
+impl Display for InvoiceItemType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            InvoiceItemType::Hours(time) => {
+                let result = write!(
+                    f,
+                    "{} hod",
+                    time.hours()
+                );
+
+                if time.minutes() > 0 {
+                    return write!(
+                        f,
+                        "{} min",
+                        time.minutes()
+                    );
+                }
+
+                result
+            }
+            InvoiceItemType::Quantity(quantity) => {
+                write!(
+                    f,
+                    "{} ks",
+                    quantity
+                )
+            }
+            InvoiceItemType::Other(other) => {
+                write!(
+                    f,
+                    "{}",
+                    other
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InvoiceItem {
+    item_type: InvoiceItemType,
+    description: String,
+    price_per_unit: Decimal,
+    #[serde(default)]
+    vat_rate: Option<VatRate>,
+    #[serde(default)]
+    price_type: PriceType,
+}
+
+impl InvoiceItem {
+    pub fn new(
+        item_type: InvoiceItemType,
+        description: impl Into<String>,
+        price_per_unit: Decimal,
+    ) -> Self {
+        Self {
+            item_type,
+            description: description.into(),
+            price_per_unit,
+            vat_rate: None,
+            price_type: PriceType::default(),
+        }
+    }
+
+    pub fn new_with_vat(
+        item_type: InvoiceItemType,
+        description: impl Into<String>,
+        price_per_unit: Decimal,
+        vat_rate: VatRate,
+        price_type: PriceType,
+    ) -> Self {
+        Self {
+            item_type,
+            description: description.into(),
+            price_per_unit,
+            vat_rate: Some(vat_rate),
+            price_type,
+        }
+    }
+
+    /// Returns the rate set on the item, items without one are taxed as [`Invoice::vat_rate`] says.
+    pub fn vat_rate(&self) -> Option<VatRate> {
+        self.vat_rate
+    }
+
+    pub fn price_type(&self) -> PriceType {
+        self.price_type
+    }
+
+    /// Renders the item row, the rate is only given to VAT payers and makes the row show the price without VAT.
+    pub fn to_html(
+        &self,
+        accounting: &accounting::Accounting,
+        vat_rate: Option<VatRate>,
+    ) -> maud::Markup {
+        html!(
+            div class="invoice-item" {
+                td class="align-right no-wrap" {
+                    (self.quantity())
+                }
+
+                td {
+                    (self.description);
+                }
+
+                td class="align-right no-wrap" {
+                    (accounting.format_money(self.price_per_unit))
+                }
+
+                @if let Some(vat_rate) = vat_rate {
+                    td class="align-right no-wrap" {
+                        (vat_rate)
+                    }
+
+                    td class="align-right no-wrap" {
+                        (accounting.format_money(self.price_without_vat(vat_rate)))
+                    }
+                } @else {
+                    td class="align-right no-wrap" {
+                        (accounting.format_money(self.price()))
+                    }
+                }
+            }
+        )
+    }
+
+    /// Returns the quantity as printed in the items table.
+    fn quantity(&self) -> String {
+        match &self.item_type {
+            InvoiceItemType::Hours(time) => {
+                format!(
+                    "{} hod",
+                    time.hour_multiplicator()
+                )
+            }
+            InvoiceItemType::Quantity(quantity) => {
+                format!(
+                    "{} ks",
+                    quantity
+                )
+            }
+            InvoiceItemType::Other(other) => other.clone(),
+        }
+    }
+
+    /// Returns a copy of the item with the opposite price, used to cancel it.
+    pub fn negated(&self) -> Self {
+        Self {
+            price_per_unit: -self.price_per_unit,
+            ..self.clone()
+        }
+    }
+
+    pub fn price(&self) -> Decimal {
+        match &self.item_type {
+            InvoiceItemType::Hours(time) => {
+                Decimal::from_f64(time.hour_multiplicator()).unwrap() * self.price_per_unit
+            }
+            InvoiceItemType::Quantity(quantity) => {
+                Decimal::from_u32(*quantity).unwrap() * self.price_per_unit
+            }
+            InvoiceItemType::Other(_) => self.price_per_unit,
+        }
+    }
+
+    /// Returns the price without VAT, VAT contained in prices with VAT is taken out at the rate.
+    pub fn price_without_vat(
+        &self,
+        vat_rate: VatRate,
+    ) -> Decimal {
+        match self.price_type {
+            PriceType::WithoutVat => self.price(),
+            PriceType::WithVat => {
+                let percentage = vat_rate.percentage();
+
+                self.price() * Decimal::ONE_HUNDRED / (Decimal::ONE_HUNDRED + percentage)
+            }
+        }
+    }
+}
+
+impl FromStr for InvoiceItem {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut parts = value.split(' ');
+
+        let item_type = parts
+            .next()
+            .ok_or("No item type")?;
+        let price_per_unit = parts
+            .next()
+            .ok_or("No price per unit")?;
+        let description = parts
+            .collect::<Vec<&str>>()
+            .join(" ");
+
+        Ok(
+            Self::new(
+                InvoiceItemType::from_str(item_type)?,
+                description,
+                price_per_unit
+                    .parse()
+                    .or(Err("Invalid price"))?,
+            ),
+        )
+    }
+}
+/// Type of the document
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+pub enum InvoiceKind {
+    #[default]
+    Invoice,
+    CreditNote(Correction),
+    /// Advance invoice (zálohová faktura), not a tax document
+    Advance,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Invoice {
+    number: Decimal,
+    #[serde(default)]
+    kind: InvoiceKind,
+    contractor: Entity,
+    client: Entity,
+    /// Account the invoice is paid to, none if the contractor has no account in the currency
+    iban: Option<Iban>,
+    #[serde(default)]
+    bic: Option<String>,
+    payment_method: PaymentMethod,
+    items: Vec<InvoiceItem>,
+    #[serde(default)]
+    advances: Vec<Advance>,
+    date: NaiveDate,
+    due_date: NaiveDate,
+    currency: Currency,
+    note: Option<String>,
+    /// Consultation number of the VIES validation of the client's VAT number
+    #[serde(default)]
+    consultation_number: Option<String>,
+    /// Konstantní symbol of the payment
+    #[serde(default)]
+    constant_symbol: Option<String>,
+    /// Specifický symbol of the payment
+    #[serde(default)]
+    specific_symbol: Option<String>,
+}
+
+impl Invoice {
+    /// Creates the invoice.
+    ///
+    /// When no IBAN is given, the contractor's account in the currency of the invoice is used.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        number: Decimal,
+        contractor: impl Into<Entity>,
+        client: impl Into<Entity>,
+        iban: Option<Iban>,
+        payment_method: PaymentMethod,
+        items: Vec<InvoiceItem>,
+        date: NaiveDate,
+        due_date: NaiveDate,
+        currency: Currency,
+        note: Option<impl Into<String>>,
+    ) -> Self {
+        let contractor = contractor.into();
+
+        let account = match iban {
+            Some(iban) => {
+                contractor
+                    .accounts()
+                    .iter()
+                    .find(|x| x.iban == iban)
+                    .cloned()
+                    .or(
+                        Some(
+                            BankAccount::new(
+                                currency, iban,
+                            ),
+                        ),
+                    )
+            }
+            None => {
+                contractor
+                    .account(currency)
+                    .cloned()
+            }
+        };
+
+        Self {
+            number,
+            kind: InvoiceKind::Invoice,
+            contractor,
+            client: client.into(),
+            iban: account
+                .as_ref()
+                .map(|x| x.iban),
+            bic: account.and_then(|x| x.bic),
+            payment_method,
+            items,
+            advances: Vec::new(),
+            date,
+            due_date,
+            currency,
+            note: note.map(|x| x.into()),
+            consultation_number: None,
+            constant_symbol: None,
+            specific_symbol: None,
+        }
+    }
+}
+
+impl Invoice {
+    pub fn kind(&self) -> &InvoiceKind {
+        &self.kind
+    }
+
+    /// Returns the account the invoice should be paid to.
+    pub fn iban(&self) -> Option<&Iban> {
+        self.iban
+            .as_ref()
+    }
+
+    /// Overrides the account picked by the currency of the invoice.
+    pub fn set_account(
+        &mut self,
+        account: &BankAccount,
+    ) {
+        self.iban = Some(account.iban);
+        self.bic = account
+            .bic
+            .clone();
+    }
+
+    /// Returns the bank of the account, looked up in the global bank code registry.
+    pub fn bank(&self) -> Option<Bank> {
+        BankRegistry::global()
+            .find(self.iban()?)
+            .cloned()
+    }
+
+    /// Returns the BIC of the account, the one of the bank from the registry if the account has none.
+    pub fn bic(&self) -> Option<String> {
+        self.bic
+            .clone()
+            .or_else(
+                || {
+                    self.bank()?
+                        .bic
+                },
+            )
+    }
+
+    /// Stores the consultation number proving the client's VAT number was valid on the invoice date.
+    pub fn set_consultation_number(
+        &mut self,
+        consultation_number: impl Into<String>,
+    ) {
+        self.consultation_number = Some(consultation_number.into());
+    }
+
+    pub fn consultation_number(&self) -> Option<&str> {
+        self.consultation_number
+            .as_deref()
+    }
+
+    /// Sets the konstantní symbol of the payment, up to 10 digits.
+    pub fn set_constant_symbol(
+        &mut self,
+        constant_symbol: impl Into<String>,
+    ) {
+        self.constant_symbol = Some(constant_symbol.into());
+    }
+
+    pub fn constant_symbol(&self) -> Option<&str> {
+        self.constant_symbol
+            .as_deref()
+    }
+
+    /// Sets the specifický symbol of the payment, up to 10 digits.
+    pub fn set_specific_symbol(
+        &mut self,
+        specific_symbol: impl Into<String>,
+    ) {
+        self.specific_symbol = Some(specific_symbol.into());
+    }
+
+    pub fn specific_symbol(&self) -> Option<&str> {
+        self.specific_symbol
+            .as_deref()
+    }
+
+    /// Returns true if the parties are from different countries, the countries are printed then.
+    pub fn shows_country(&self) -> bool {
+        self.contractor
+            .country_code()
+            != self
+                .client
+                .country_code()
+    }
+
+    /// Returns the heading of the document.
+    pub fn title(&self) -> &'static str {
+        match self.kind {
+            InvoiceKind::Invoice => "Faktura",
+            InvoiceKind::Advance => "Zálohová faktura",
+            InvoiceKind::CreditNote(_) => {
+                if self
+                    .contractor
+                    .is_vat_payer()
+                {
+                    "Opravný daňový doklad"
+                } else {
+                    "Opravná faktura"
+                }
+            }
+        }
+    }
+
+    /// Returns whether the document is a tax document (daňový doklad).
+    pub fn is_tax_document(&self) -> bool {
+        self.kind != InvoiceKind::Advance
+            && self
+                .contractor
+                .is_vat_payer()
+    }
+
+    /// Returns the VAT rate of the item on this invoice.
+    ///
+    /// Items of non VAT payers are exempt, items of VAT payers without a rate of their own are
+    /// taxed at the standard rate.
+    pub fn vat_rate(
+        &self,
+        item: &InvoiceItem,
+    ) -> VatRate {
+        if !self
+            .contractor
+            .is_vat_payer()
+        {
+            return VatRate::Exempt;
+        }
+
+        item.vat_rate
+            .unwrap_or(VatRate::Standard)
+    }
+
+    /// Computes the VAT recapitulation, one row per VAT rate used on the invoice.
+    pub fn vat_recapitulation(&self) -> Vec<VatSummary> {
+        let mut sums: BTreeMap<
+            VatRate,
+            (
+                Decimal,
+                Decimal,
+            ),
+        > = BTreeMap::new();
+
+        for item in &self.items {
+            let (without_vat, with_vat) = sums
+                .entry(self.vat_rate(item))
+                .or_default();
+
+            match item.price_type {
+                PriceType::WithoutVat => *without_vat += item.price(),
+                PriceType::WithVat => *with_vat += item.price(),
+            }
+        }
+
+        let decimals = self
+            .currency
+            .exponent()
+            .unwrap_or(2) as u32;
+
+        sums.into_iter()
+            .map(
+                |(rate, (without_vat, with_vat))| {
+                    VatSummary::new(
+                        rate,
+                        without_vat,
+                        with_vat,
+                        decimals,
+                    )
+                },
+            )
+            .collect()
+    }
+
+    /// Returns the total amount of the invoice.
+    ///
+    /// VAT is only added when the contractor is a VAT payer.
+    pub fn total(&self) -> Decimal {
+        if self
+            .contractor
+            .is_vat_payer()
+        {
+            return self
+                .vat_recapitulation()
+                .iter()
+                .map(|x| x.total)
+                .sum();
+        }
+
+        self.items
+            .iter()
+            .map(|x| x.price())
+            .sum()
+    }
+
+    /// Renders the invoice as HTML, fails if the payment QR code can't be built.
+    pub fn to_html(&self) -> Result<maud::Markup, payment_code::Error> {
+        let ac = accounting::create_accounting_from_currency(self.currency);
+
+        let vat_payer = self
+            .contractor
+            .is_vat_payer();
+        let vat_recapitulation = self.vat_recapitulation();
+        let show_country = self.shows_country();
+        let bank_account = self
+            .iban()
+            .map(|x| x.to_bank_account_number());
+        let iban = self
+            .iban()
+            .map(|x| x.to_string());
+        let bank = self.bank();
+        let bic = self.bic();
+
+        let items_sum = self.total();
+        let balance = self.balance();
+
+        let qr_code = self.qr_code(&balance)?;
+
+        Ok(
+            html!(
+                (DOCTYPE)
+                html {
+                    head {
+                        title { (self.title()) " " (self.number) };
+                        link rel="stylesheet" href="templates/style.css";
+                    }
+                    body {
+                        div class="space-between block" {
+                            div {}
+                            div class="block-right" {
+                                h1 class="line-above-bold" {
+                                    (self.title()) " " span .invoice-number { (self.number) }
+                                }
+                            }
+                        }
+                        div class="block" {
+                            div class="entity" {
+                                h2 { "DODAVATEL" }
+                                (self.contractor.to_html(show_country));
+                            }
+
+                            div class="entity block-right" {
+                                h2 { "ODBĚRATEL" }
+                                (self.client.to_html(show_country));
+                            }
+                        }
+
+                        div class = "block" {
+                            div class = "payment-info" {
+                                @if let (Some(bank_account), Some(iban)) = (&bank_account, &iban) {
+                                    div class="space-between" {
+                                        p class="text-grayed" {
+                                            "Bankovní účet"
+                                        }
+
+                                        p {
+                                            (bank_account)
+                                        }
+                                    }
+
+                                    @if bank_account != iban {
+                                        div class="space-between" {
+                                            p class="text-grayed" {
+                                                "IBAN"
+                                            }
+
+                                            p {
+                                                (iban)
+                                            }
+                                        }
+                                    }
+                                }
+
+                                @if let Some(bank) = &bank {
+                                    div class="space-between" {
+                                        p class="text-grayed" {
+                                            "Banka"
+                                        }
+
+                                        p {
+                                            (bank.name)
+                                        }
+                                    }
+                                }
+
+                                @if let Some(bic) = &bic {
+                                    div class="space-between" {
+                                        p class="text-grayed" {
+                                            "SWIFT/BIC"
+                                        }
+
+                                        p {
+                                            (bic)
+                                        }
+                                    }
+                                }
+
+                                @if let PaymentMethod::BankTransfer(var_symbol) = &self.payment_method {
+                                    div class="space-between" {
+                                        p class = "text-grayed" {
+                                            "Variabilní symbol"
+                                        }
+
+                                        p {
+                                            (var_symbol)
+                                        }
+                                    }
+                                }
+
+                                @if let Some(constant_symbol) = &self.constant_symbol {
+                                    div class="space-between" {
+                                        p class = "text-grayed" {
+                                            "Konstantní symbol"
+                                        }
+
+                                        p {
+                                            (constant_symbol)
+                                        }
+                                    }
+                                }
+
+                                @if let Some(specific_symbol) = &self.specific_symbol {
+                                    div class="space-between" {
+                                        p class = "text-grayed" {
+                                            "Specifický symbol"
+                                        }
+
+                                        p {
+                                            (specific_symbol)
+                                        }
+                                    }
+                                }
+
+                                div class="space-between" {
+                                    p class="text-grayed" {
+                                        "Způsob platby"
+                                    }
+                                    p {
+                                        (self.payment_method.name())
+                                    }
+                                }
+                            }
+
+                            div class="dates block-right" {
+                                div class="space-between" {
+                                    p class="text-grayed" {
+                                        "Datum vystavení"
+                                    }
+
+                                    p {
+                                        (self.date.format(DATE_FORMAT));
+                                    }
+                                }
+
+                                div class="space-between" {
+                                    p class="text-grayed" {
+                                        "Datum splatnosti"
+                                    }
+
+                                    p {
+                                        (self.due_date.format(DATE_FORMAT));
+                                    }
+                                }
+
+                                @if let Some(correction) = self.correction() {
+                                    div class="space-between" {
+                                        p class="text-grayed" {
+                                            "Opravovaný doklad"
+                                        }
+
+                                        p {
+                                            (correction.original_number)
+                                        }
+                                    }
+
+                                    div class="space-between" {
+                                        p class="text-grayed" {
+                                            "Ze dne"
+                                        }
+
+                                        p {
+                                            (correction.original_date.format(DATE_FORMAT));
+                                        }
+                                    }
+                                }
+                            }
+
+                        }
+
+                        @if let Some(correction) = self.correction() {
+                            div class="block" {
+                                p {
+                                    span class="text-grayed" { "Důvod opravy: " }
+                                    (correction.reason)
+                                }
+                            }
+                        }
+                        table class="invoice-items line-below" {
+                            thead class="line-below" {
+                                tr {
+                                    th class="align-right no-wrap" { "" }
+                                    th { "" }
+                                    th class="align-right no-wrap" { "CENA ZA MJ" }
+                                    @if vat_payer {
+                                        th class="align-right no-wrap" { "DPH" }
+                                        th class="align-right no-wrap" { "CELKEM BEZ DPH" }
+                                    } @else {
+                                        th class="align-right no-wrap" { "CELKEM" }
+                                    }
+                                }
+                            }
+                            @for item in &self.items {
+                                tr {
+                                    ({
+                                        item.to_html(&ac, vat_payer.then(|| self.vat_rate(item)))
+                                    });
+                                }
+                            }
+                        }
+
+                        @if self.is_tax_document() {
+                            table class="vat-recapitulation line-below" {
+                                thead class="line-below" {
+                                    tr {
+                                        th { "REKAPITULACE DPH" }
+                                        th class="align-right no-wrap" { "ZÁKLAD" }
+                                        th class="align-right no-wrap" { "DPH" }
+                                        th class="align-right no-wrap" { "CELKEM" }
+                                    }
+                                }
+                                @for summary in &vat_recapitulation {
+                                    tr {
+                                        td { (summary.rate) }
+                                        td class="align-right no-wrap" { (ac.format_money(summary.base)) }
+                                        td class="align-right no-wrap" { (ac.format_money(summary.vat)) }
+                                        td class="align-right no-wrap" { (ac.format_money(summary.total)) }
+                                    }
+                                }
+                            }
+                        }
+
+                        @if !self.advances.is_empty() {
+                            table class="advances line-below" {
+                                tr {
+                                    td { "Celkem" }
+                                    td class="align-right no-wrap" { (ac.format_money(items_sum)) }
+                                }
+                                @for advance in &self.advances {
+                                    tr {
+                                        td { "Uhrazená záloha č. " (advance.number) }
+                                        td class="align-right no-wrap" { (ac.format_money(-advance.amount)) }
+                                    }
+                                }
+                            }
+                        }
+
+                        div class="space-between block" {
+                            div {
+                                div class = "qr" {
+                                    @if let Some(qr_code) = qr_code {
+                                        (PreEscaped(qr_code))
+                                    }
+                                }
+                            }
+
+                            div class = "line-above-bold block-right border-black" {
+                                @if !self.advances.is_empty() {
+                                    p class = "text-grayed align-right" {
+                                        "K úhradě"
+                                    }
+                                }
+                                p class = "text-bold text-big align-right" {
+                                    (ac.format_money(balance))
+                                }
+                            }
+                        }
+
+                        @if self.kind == InvoiceKind::Advance {
+                            div class = "block" {
+                                p class = "text-grayed" {
+                                    "Tento doklad není daňovým dokladem."
+                                }
+                            }
+                        }
+
+                        @if let Some(note) = &self.note {
+                            div class = "note" {
+                                p {
+                                    (note)
+                                }
+                            }
+                        }
+                    }
+                }
+            ),
+        )
+    }
+}
+
+pub trait ToBankAccountNumber {
+    fn to_bank_account_number(&self) -> String;
+}
+
+/// Czech and Slovak accounts are written in the domestic format, other accounts as the IBAN.
+impl ToBankAccountNumber for Iban {
+    fn to_bank_account_number(&self) -> String {
+        match CzBankAccount::try_from(self) {
+            Ok(account) => account.to_string(),
+            Err(_) => self.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iban::IbanLike;
+
+    use super::*;
+    use crate::entity::eu;
+    use crate::fixtures;
+
+    fn invoice(
+        contractor: eu::Entity,
+        iban: Option<Iban>,
+        currency: Currency,
+    ) -> Invoice {
+        fixtures::invoice(
+            contractor.clone(),
+            contractor,
+            iban,
+            currency,
+        )
+    }
+
+    #[test]
+    fn test_default_vat_rate() {
+        let mut contractor = fixtures::entity("27082440");
+
+        let invoice_exempt = invoice(
+            contractor.clone(),
+            None,
+            Currency::CZK,
+        );
+
+        assert_eq!(
+            invoice_exempt.vat_rate(&invoice_exempt.items[0]),
+            VatRate::Exempt
+        );
+        assert_eq!(
+            invoice_exempt.total(),
+            Decimal::new(1000, 0)
+        );
+
+        contractor.vat_number = Some(
+            "CZ27082440"
+                .parse()
+                .unwrap(),
+        );
+
+        let mut invoice = invoice(
+            contractor,
+            None,
+            Currency::CZK,
+        );
+
+        assert_eq!(
+            invoice.vat_rate(&invoice.items[0]),
+            VatRate::Standard
+        );
+        assert_eq!(
+            invoice.total(),
+            Decimal::new(1210, 0)
+        );
+
+        invoice.items = vec![
+            InvoiceItem::new_with_vat(
+                InvoiceItemType::Quantity(1),
+                "Kniha",
+                Decimal::new(
+                    112, 0,
+                ),
+                VatRate::Reduced,
+                PriceType::WithVat,
+            ),
+        ];
+
+        assert_eq!(
+            invoice.vat_rate(&invoice.items[0]),
+            VatRate::Reduced
+        );
+        assert_eq!(
+            invoice.items[0].price_without_vat(VatRate::Reduced),
+            Decimal::new(100, 0)
+        );
+        assert_eq!(
+            invoice.total(),
+            Decimal::new(112, 0)
+        );
+    }
+
+    #[test]
+    fn test_account_selection() {
+        let czk: Iban = "CZ6508000000192000145399"
+            .parse()
+            .unwrap();
+        let eur: Iban = "SK3112000000198742637541"
+            .parse()
+            .unwrap();
+
+        let mut contractor = fixtures::entity("27082440");
+        contractor
+            .accounts
+            .push(
+                BankAccount::new(
+                    Currency::CZK,
+                    czk,
+                ),
+            );
+        contractor
+            .accounts
+            .push(
+                BankAccount {
+                    bic: Some("TATRSKBX".to_string()),
+                    label: Some("EUR".to_string()),
+                    ..BankAccount::new(
+                        Currency::EUR,
+                        eur,
+                    )
+                },
+            );
+
+        let invoice_eur = invoice(
+            contractor.clone(),
+            None,
+            Currency::EUR,
+        );
+
+        assert_eq!(
+            invoice_eur.iban(),
+            Some(&eur)
+        );
+        assert_eq!(
+            invoice_eur
+                .bic()
+                .as_deref(),
+            Some("TATRSKBX")
+        );
+        let spayd = invoice_eur
+            .spayd(&invoice_eur.total())
+            .unwrap()
+            .unwrap();
+
+        assert!(
+            spayd.contains(
+                &format!(
+                    "{}+TATRSKBX",
+                    eur.electronic_str()
+                )
+            )
+        );
+
+        let mut invoice_czk = invoice(
+            contractor.clone(),
+            None,
+            Currency::CZK,
+        );
+
+        assert_eq!(
+            invoice_czk.iban(),
+            Some(&czk)
+        );
+
+        invoice_czk.set_account(&contractor.accounts[1]);
+
+        assert_eq!(
+            invoice_czk.iban(),
+            Some(&eur)
+        );
+
+        let invoice_usd = invoice(
+            contractor.clone(),
+            None,
+            Currency::USD,
+        );
+
+        assert!(
+            invoice_usd
+                .iban()
+                .is_none()
+        );
+        assert!(
+            invoice_usd
+                .spayd(&invoice_usd.total())
+                .unwrap()
+                .is_none()
+        );
+
+        let invoice_override = invoice(
+            contractor,
+            Some(czk),
+            Currency::EUR,
+        );
+
+        assert_eq!(
+            invoice_override.iban(),
+            Some(&czk)
+        );
+    }
+
+    #[test]
+    fn test_baseline_invoice() {
+        // Invoice saved by the versions storing only EU entities, without a tag
+        let stored = r#"{
+            "number": "202401",
+            "contractor": {
+                "identifier": "27082440",
+                "name": "Firma s.r.o.",
+                "address": {
+                    "city": "Praha",
+                    "street": "Husova",
+                    "postal_code": "12000",
+                    "house_number": 123,
+                    "orientation_number": 4
+                },
+                "vat_number": "CZ27082440"
+            },
+            "client": {
+                "identifier": "25596641",
+                "name": "Klient s.r.o.",
+                "address": {
+                    "city": "Praha",
+                    "street": "Husova",
+                    "postal_code": "12000",
+                    "house_number": 123,
+                    "orientation_number": null
+                },
+                "vat_number": null
+            },
+            "iban": "CZ65 0800 0000 1920 0014 5399",
+            "payment_method": {
+                "BankTransfer": "202401"
+            },
+            "items": [
+                {
+                    "item_type": {
+                        "Hours": [10, 30]
+                    },
+                    "description": "Programování",
+                    "price_per_unit": "1000"
+                },
+                {
+                    "item_type": {
+                        "Quantity": 2
+                    },
+                    "description": "Licence",
+                    "price_per_unit": "500"
+                }
+            ],
+            "date": "2024-03-01",
+            "due_date": "2024-03-15",
+            "currency": "CZK",
+            "note": "Děkujeme"
+        }"#;
+
+        let invoice: Invoice = serde_json::from_str(stored).unwrap();
+
+        assert_eq!(
+            invoice
+                .contractor
+                .identifier(),
+            "27082440"
+        );
+        assert!(
+            invoice
+                .contractor
+                .is_vat_payer()
+        );
+        assert_eq!(
+            invoice
+                .client
+                .name(),
+            "Klient s.r.o."
+        );
+        assert_eq!(
+            invoice.total(),
+            Decimal::new(13915, 0)
+        );
+
+        let saved = serde_json::to_value(&invoice).unwrap();
+
+        assert!(saved["contractor"]["Eu"].is_object());
+        assert_eq!(
+            serde_json::from_value::<Invoice>(saved)
+                .unwrap()
+                .contractor,
+            invoice.contractor
+        );
+    }
+}
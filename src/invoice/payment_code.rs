@@ -0,0 +1,764 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use fast_qr::convert::svg::SvgBuilder;
+use fast_qr::convert::Builder;
+use fast_qr::convert::Shape;
+use fast_qr::qr;
+use fast_qr::ECL;
+use iban::IbanLike;
+use iso_currency::Currency;
+use rust_decimal::Decimal;
+use spayd::Spayd;
+
+use super::Invoice;
+use crate::country::CountryCode;
+use crate::payment_method::PaymentMethod;
+
+/// Longest values allowed by the SPAYD specification
+const MAX_AMOUNT: usize = 10;
+const MAX_RECIPIENT_NAME: usize = 35;
+const MAX_MESSAGE: usize = 60;
+const MAX_SYMBOL: usize = 10;
+const MAX_ALTERNATIVE_ACCOUNTS: usize = 2;
+/// Longest SPAYD payload, the size of the QR code version 13 at the level M banking apps read reliably
+const MAX_PAYLOAD: usize = 331;
+
+/// Limits of the EPC069-12 payment code, the amount is given in cents and the texts in bytes
+const MAX_EPC_AMOUNT: i64 = 99_999_999_999;
+const MAX_BENEFICIARY_NAME: usize = 70;
+const MAX_REMITTANCE: usize = 140;
+const MAX_EPC_PAYLOAD: usize = 331;
+
+/// Standard of the payment QR code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Standard {
+    /// Short Payment Descriptor read by Czech banking apps
+    Spayd,
+    /// EPC069-12 SEPA credit transfer ("GiroCode") read by banking apps in the eurozone
+    Epc,
+}
+
+/// Errors that can occur while building the payment QR code
+#[derive(Debug)]
+pub enum Error {
+    /// The value of the field doesn't fit the payment code
+    InvalidValue(&'static str),
+    QrError,
+}
+
+impl Display for Error {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Error::InvalidValue(field) => {
+                write!(
+                    f,
+                    "Invalid value of the payment code field: {}",
+                    field
+                )
+            }
+            Error::QrError => {
+                write!(
+                    f,
+                    "Failed to build the QR code"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Replaces the letters with diacritics by their ASCII base, banking apps don't read other characters reliably.
+///
+/// `*` separates the fields of the payment code, so it's replaced by a space.
+pub(crate) fn transliterate(value: &str) -> String {
+    value
+        .chars()
+        .filter_map(
+            |ch| {
+                let ascii = match ch {
+                    '*' => " ",
+                    ch if ch.is_ascii() => return Some(ch.to_string()),
+                    'á' | 'à' | 'â' | 'ä' | 'ã' | 'å' | 'ą' => "a",
+                    'Á' | 'À' | 'Â' | 'Ä' | 'Ã' | 'Å' | 'Ą' => "A",
+                    'č' | 'ć' | 'ç' => "c",
+                    'Č' | 'Ć' | 'Ç' => "C",
+                    'ď' | 'đ' => "d",
+                    'Ď' | 'Đ' => "D",
+                    'é' | 'è' | 'ê' | 'ë' | 'ě' | 'ę' => "e",
+                    'É' | 'È' | 'Ê' | 'Ë' | 'Ě' | 'Ę' => "E",
+                    'í' | 'ì' | 'î' | 'ï' => "i",
+                    'Í' | 'Ì' | 'Î' | 'Ï' => "I",
+                    'ĺ' | 'ľ' | 'ł' => "l",
+                    'Ĺ' | 'Ľ' | 'Ł' => "L",
+                    'ň' | 'ń' | 'ñ' => "n",
+                    'Ň' | 'Ń' | 'Ñ' => "N",
+                    'ó' | 'ò' | 'ô' | 'ö' | 'õ' | 'ő' | 'ø' => "o",
+                    'Ó' | 'Ò' | 'Ô' | 'Ö' | 'Õ' | 'Ő' | 'Ø' => "O",
+                    'ř' | 'ŕ' => "r",
+                    'Ř' | 'Ŕ' => "R",
+                    'š' | 'ś' | 'ş' => "s",
+                    'Š' | 'Ś' | 'Ş' => "S",
+                    'ß' => "ss",
+                    'ť' | 'ţ' => "t",
+                    'Ť' | 'Ţ' => "T",
+                    'ú' | 'ù' | 'û' | 'ü' | 'ů' | 'ű' => "u",
+                    'Ú' | 'Ù' | 'Û' | 'Ü' | 'Ů' | 'Ű' => "U",
+                    'ý' | 'ÿ' => "y",
+                    'Ý' | 'Ÿ' => "Y",
+                    'ž' | 'ź' | 'ż' => "z",
+                    'Ž' | 'Ź' | 'Ż' => "Z",
+                    'æ' => "ae",
+                    'Æ' => "AE",
+                    _ => return None,
+                };
+
+                Some(ascii.to_string())
+            },
+        )
+        .collect()
+}
+
+/// Transliterates the text and cuts it to the length allowed for the field
+fn text(
+    value: &str,
+    max: usize,
+) -> String {
+    transliterate(value)
+        .trim()
+        .chars()
+        .take(max)
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+/// Cuts the text to at most `max` bytes of UTF-8 without splitting a character
+fn truncate(
+    value: &str,
+    max: usize,
+) -> &str {
+    let mut end = value
+        .len()
+        .min(max);
+
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    &value[..end]
+}
+
+/// Checks the payment symbol, up to 10 digits
+fn symbol(
+    value: &str,
+    field: &'static str,
+) -> Result<String, Error> {
+    let value = value.trim();
+
+    match value.len() <= MAX_SYMBOL
+        && value
+            .chars()
+            .all(|x| x.is_ascii_digit())
+    {
+        true => Ok(value.to_string()),
+        false => Err(Error::InvalidValue(field)),
+    }
+}
+
+/// Account written as `IBAN+BIC`, the BIC is optional
+fn account(
+    iban: &impl IbanLike,
+    bic: Option<&str>,
+) -> String {
+    match bic {
+        Some(bic) => {
+            format!(
+                "{}+{}",
+                iban.electronic_str(),
+                bic
+            )
+        }
+        None => {
+            iban.electronic_str()
+                .to_string()
+        }
+    }
+}
+
+impl Invoice {
+    /// Builds the SPAYD payment string for the QR code.
+    ///
+    /// There is no payment code when the invoice isn't paid by a bank transfer or has no account.
+    /// SPAYD can't express negative amounts, so there is no payment to be made
+    /// by the client when the amount isn't positive.
+    pub(crate) fn spayd(
+        &self,
+        items_sum: &Decimal,
+    ) -> Result<Option<String>, Error> {
+        let PaymentMethod::BankTransfer(variable_symbol) = &self.payment_method else {
+            return Ok(None);
+        };
+
+        let Some(iban) = self.iban() else {
+            return Ok(None);
+        };
+
+        if *items_sum <= Decimal::ZERO {
+            return Ok(None);
+        }
+
+        let amount = items_sum
+            .round_dp(2)
+            .to_string();
+
+        if amount.len() > MAX_AMOUNT {
+            return Err(Error::InvalidValue("AM"));
+        }
+
+        let bic = self.bic();
+
+        // Other accounts of the contractor in the same currency, the bank app may offer them instead
+        let alternative_accounts = self
+            .contractor
+            .accounts()
+            .iter()
+            .filter(|x| x.currency == self.currency && x.iban != *iban)
+            .take(MAX_ALTERNATIVE_ACCOUNTS)
+            .map(
+                |x| {
+                    account(
+                        &x.iban,
+                        x.bic
+                            .as_deref(),
+                    )
+                },
+            )
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let mut fields = vec![
+            (
+                spayd::fields::ACCOUNT,
+                account(
+                    iban,
+                    bic.as_deref(),
+                ),
+            ),
+            (
+                spayd::fields::AMOUNT,
+                amount,
+            ),
+            (
+                spayd::fields::CURRENCY,
+                self.currency
+                    .code()
+                    .to_string(),
+            ),
+            (
+                "DT",
+                self.due_date
+                    .format("%Y%m%d")
+                    .to_string(),
+            ),
+            (
+                "RN",
+                text(
+                    self.contractor
+                        .name(),
+                    MAX_RECIPIENT_NAME,
+                ),
+            ),
+            (
+                "MSG",
+                text(
+                    &format!(
+                        "{} {}",
+                        self.title(),
+                        self.number
+                    ),
+                    MAX_MESSAGE,
+                ),
+            ),
+        ];
+
+        if !alternative_accounts.is_empty() {
+            fields.push(
+                (
+                    "ALT-ACC",
+                    alternative_accounts,
+                ),
+            );
+        }
+
+        if !variable_symbol
+            .trim()
+            .is_empty()
+        {
+            fields.push(
+                (
+                    "X-VS",
+                    symbol(
+                        variable_symbol,
+                        "X-VS",
+                    )?,
+                ),
+            );
+        }
+
+        if let Some(constant_symbol) = &self.constant_symbol {
+            fields.push(
+                (
+                    "X-KS",
+                    symbol(
+                        constant_symbol,
+                        "X-KS",
+                    )?,
+                ),
+            );
+        }
+
+        if let Some(specific_symbol) = &self.specific_symbol {
+            fields.push(
+                (
+                    "X-SS",
+                    symbol(
+                        specific_symbol,
+                        "X-SS",
+                    )?,
+                ),
+            );
+        }
+
+        let spayd = Spayd::new_v1_0(
+            fields
+                .iter()
+                .map(
+                    |(key, value)| {
+                        (
+                            *key, value,
+                        )
+                    },
+                ),
+        )
+        .to_string();
+
+        // The fields are limited one by one, escaping and the alternative accounts add up on top of them
+        if spayd.len() > MAX_PAYLOAD {
+            return Err(Error::InvalidValue("SPAYD"));
+        }
+
+        Ok(Some(spayd))
+    }
+
+    /// Builds the EPC069-12 payload of a SEPA credit transfer for the QR code.
+    ///
+    /// There is no payment code when the invoice isn't paid by a bank transfer in EUR, has no account
+    /// or the amount isn't positive.
+    pub(crate) fn epc(
+        &self,
+        items_sum: &Decimal,
+    ) -> Result<Option<String>, Error> {
+        let PaymentMethod::BankTransfer(variable_symbol) = &self.payment_method else {
+            return Ok(None);
+        };
+
+        let Some(iban) = self.iban() else {
+            return Ok(None);
+        };
+
+        if *items_sum <= Decimal::ZERO || self.currency != Currency::EUR {
+            return Ok(None);
+        }
+
+        let amount = items_sum.round_dp(2);
+
+        if amount
+            > Decimal::new(
+                MAX_EPC_AMOUNT,
+                2,
+            )
+        {
+            return Err(Error::InvalidValue("Amount"));
+        }
+
+        let name = truncate(
+            self.contractor
+                .name()
+                .trim(),
+            MAX_BENEFICIARY_NAME,
+        );
+
+        if name.is_empty() {
+            return Err(Error::InvalidValue("Name"));
+        }
+
+        let mut remittance = format!(
+            "{} {}",
+            self.title(),
+            self.number
+        );
+
+        if !variable_symbol
+            .trim()
+            .is_empty()
+        {
+            remittance.push_str(
+                &format!(
+                    ", VS {}",
+                    variable_symbol.trim()
+                ),
+            );
+        }
+
+        let payload = [
+            "BCD".to_string(),
+            // Version 002 makes the BIC optional
+            "002".to_string(),
+            // UTF-8
+            "1".to_string(),
+            "SCT".to_string(),
+            self.bic()
+                .unwrap_or_default(),
+            name.to_string(),
+            iban.electronic_str()
+                .to_string(),
+            format!(
+                "EUR{:.2}",
+                amount
+            ),
+            // Purpose and structured remittance aren't used
+            String::new(),
+            String::new(),
+            truncate(
+                &remittance,
+                MAX_REMITTANCE,
+            )
+            .to_string(),
+        ]
+        .join("\n");
+
+        if payload.len() > MAX_EPC_PAYLOAD {
+            return Err(Error::InvalidValue("Payload"));
+        }
+
+        Ok(Some(payload))
+    }
+
+    /// Returns the standard of the payment QR code.
+    ///
+    /// EUR invoices to clients in the SEPA area outside the Czech Republic use the EPC QR code,
+    /// the other invoices SPAYD.
+    pub fn payment_code_standard(&self) -> Standard {
+        let sepa_client = self
+            .client
+            .as_eu()
+            .is_some_and(
+                |x| {
+                    x.address
+                        .country
+                        .is_sepa()
+                        && x.address
+                            .country
+                            != CountryCode::Cz
+                },
+            );
+
+        match self.currency == Currency::EUR && sepa_client {
+            true => Standard::Epc,
+            false => Standard::Spayd,
+        }
+    }
+
+    /// Builds the payload of the payment QR code in the standard chosen for the invoice.
+    pub(crate) fn payment_code(
+        &self,
+        items_sum: &Decimal,
+    ) -> Result<Option<String>, Error> {
+        match self.payment_code_standard() {
+            Standard::Spayd => self.spayd(items_sum),
+            Standard::Epc => self.epc(items_sum),
+        }
+    }
+
+    /// Builds the payment QR code, EPC requires the error correction level M.
+    pub(crate) fn qr(
+        &self,
+        items_sum: &Decimal,
+    ) -> Result<Option<qr::QRCode>, Error> {
+        let Some(payload) = self.payment_code(items_sum)? else {
+            return Ok(None);
+        };
+
+        let mut builder = qr::QRBuilder::new(payload);
+
+        if self.payment_code_standard() == Standard::Epc {
+            builder.ecl(ECL::M);
+        }
+
+        builder
+            .build()
+            .map(Some)
+            .or(Err(Error::QrError))
+    }
+
+    /// Renders the payment QR code as SVG.
+    pub(crate) fn qr_code(
+        &self,
+        items_sum: &Decimal,
+    ) -> Result<Option<String>, Error> {
+        let Some(qr) = self.qr(items_sum)? else {
+            return Ok(None);
+        };
+
+        Ok(
+            Some(
+                SvgBuilder::default()
+                    .shape(Shape::RoundedSquare)
+                    .background_color(
+                        [
+                            255, 255, 255, 0,
+                        ],
+                    )
+                    .margin(0)
+                    .to_str(&qr),
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bank_account::BankAccount;
+    use crate::fixtures;
+    use crate::fixtures::entity;
+    use crate::fixtures::iban;
+
+    fn invoice(
+        client: &str,
+        currency: Currency,
+    ) -> Invoice {
+        fixtures::invoice(
+            entity("27082440"),
+            entity(client),
+            Some(iban()),
+            currency,
+        )
+    }
+
+    #[test]
+    fn test_standard() {
+        assert_eq!(
+            invoice(
+                "25596641",
+                Currency::CZK
+            )
+            .payment_code_standard(),
+            Standard::Spayd
+        );
+        assert_eq!(
+            invoice(
+                "25596641",
+                Currency::EUR
+            )
+            .payment_code_standard(),
+            Standard::Spayd
+        );
+        assert_eq!(
+            invoice(
+                "DE:HRB 86891 B",
+                Currency::EUR
+            )
+            .payment_code_standard(),
+            Standard::Epc
+        );
+        assert_eq!(
+            invoice(
+                "CH:CHE-109322551",
+                Currency::EUR
+            )
+            .payment_code_standard(),
+            Standard::Epc
+        );
+        assert_eq!(
+            invoice(
+                "JP:1234567890123",
+                Currency::EUR
+            )
+            .payment_code_standard(),
+            Standard::Spayd
+        );
+    }
+
+    #[test]
+    fn test_spayd() {
+        let mut invoice = invoice(
+            "25596641",
+            Currency::CZK,
+        );
+        invoice.set_constant_symbol("0308");
+        invoice.set_specific_symbol("42");
+
+        assert_eq!(
+            invoice
+                .spayd(&invoice.total())
+                .unwrap()
+                .unwrap(),
+            "SPD*1.0*ACC:CZ6508000000192000145399+GIBACZPX*AM:1000*CC:CZK*DT:20240301*MSG:Faktura 202401*RN:Firma s.r.o.*X-KS:0308*X-SS:42*X-VS:202401"
+        );
+
+        invoice.set_specific_symbol("2024/01");
+
+        assert!(
+            matches!(
+                invoice.spayd(&invoice.total()),
+                Err(Error::InvalidValue("X-SS"))
+            )
+        );
+    }
+
+    #[test]
+    fn test_spayd_length() {
+        let mut contractor = entity("27082440");
+
+        for iban in [
+            "CZ5508000000001234567899",
+            "CZ6907101781240000004159",
+        ] {
+            contractor
+                .accounts
+                .push(
+                    BankAccount {
+                        bic: Some("GIBACZPXXXX".to_string()),
+                        ..BankAccount::new(
+                            Currency::CZK,
+                            iban.parse()
+                                .unwrap(),
+                        )
+                    },
+                );
+        }
+
+        let invoice = fixtures::invoice(
+            contractor.clone(),
+            entity("25596641"),
+            Some(iban()),
+            Currency::CZK,
+        );
+        let spayd = invoice
+            .spayd(&invoice.total())
+            .unwrap()
+            .unwrap();
+
+        assert!(spayd.contains("*ALT-ACC:CZ5508000000001234567899+GIBACZPXXXX,CZ6907101781240000004159+GIBACZPXXXX*"));
+
+        // Every % of the name takes three characters in the payload
+        contractor.name = "%".repeat(MAX_RECIPIENT_NAME);
+
+        let mut invoice = fixtures::invoice(
+            contractor,
+            entity("25596641"),
+            Some(iban()),
+            Currency::CZK,
+        );
+        invoice.set_constant_symbol("0123456789");
+        invoice.set_specific_symbol("0123456789");
+
+        let spayd = invoice
+            .spayd(&invoice.total())
+            .unwrap()
+            .unwrap();
+
+        assert!(spayd.contains("ALT-ACC"));
+        assert!(spayd.len() <= MAX_PAYLOAD);
+    }
+
+    #[test]
+    fn test_epc() {
+        let eur = invoice(
+            "DE:HRB 86891 B",
+            Currency::EUR,
+        );
+
+        assert_eq!(
+            eur.payment_code(&eur.total())
+                .unwrap()
+                .unwrap(),
+            "BCD\n002\n1\nSCT\nGIBACZPX\nFirma s.r.o.\nCZ6508000000192000145399\nEUR1000.00\n\n\nFaktura 202401, VS 202401"
+        );
+        assert!(
+            eur.qr_code(&eur.total())
+                .unwrap()
+                .is_some()
+        );
+
+        let czk = invoice(
+            "DE:HRB 86891 B",
+            Currency::CZK,
+        );
+
+        assert!(
+            czk.epc(&czk.total())
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_transliterate() {
+        assert_eq!(
+            transliterate("Příliš žluťoučký kůň * Straße"),
+            "Prilis zlutoucky kun   Strasse"
+        );
+        assert_eq!(
+            text(
+                "Faktura - daňový doklad 202401",
+                10
+            ),
+            "Faktura -"
+        );
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(
+            truncate(
+                "Příliš",
+                2
+            ),
+            "P"
+        );
+        assert_eq!(
+            truncate(
+                "Příliš",
+                3
+            ),
+            "Př"
+        );
+        assert_eq!(
+            truncate("Firma", 70),
+            "Firma"
+        );
+    }
+
+    #[test]
+    fn test_symbol() {
+        assert_eq!(
+            symbol(" 0308 ", "X-KS").unwrap(),
+            "0308"
+        );
+        assert!(
+            symbol(
+                "12345678901",
+                "X-VS"
+            )
+            .is_err()
+        );
+        assert!(symbol("2024/01", "X-VS").is_err());
+    }
+}
@@ -0,0 +1,1693 @@
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use fast_qr::qr;
+use printpdf::Color;
+use printpdf::Greyscale;
+use printpdf::IndirectFontRef;
+use printpdf::Line;
+use printpdf::Mm;
+use printpdf::OffsetDateTime;
+use printpdf::PdfDocument;
+use printpdf::PdfDocumentReference;
+use printpdf::PdfLayerReference;
+use printpdf::Point;
+use printpdf::Rect;
+use ttf_parser::Face;
+use ttf_parser::FaceParsingError;
+use ttf_parser::RawFace;
+use ttf_parser::Tag;
+use uuid::Uuid;
+
+use super::payment_code;
+use super::Invoice;
+use super::InvoiceKind;
+use super::ToBankAccountNumber;
+use super::DATE_FORMAT;
+use crate::accounting;
+use crate::entity::Entity;
+use crate::payment_method::PaymentMethod;
+
+const REGULAR_FONT: &[u8] = include_bytes!("../../assets/NotoSans-Regular.ttf");
+const BOLD_FONT: &[u8] = include_bytes!("../../assets/NotoSans-Bold.ttf");
+
+const PT_TO_MM: f32 = 0.352_778;
+const PX_TO_PT: f32 = 0.75;
+
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 10.0;
+const COLUMN_GAP: f32 = 10.0;
+const COLUMN_WIDTH: f32 = (PAGE_WIDTH - 2.0 * MARGIN - COLUMN_GAP) / 2.0;
+const LEFT_COLUMN: f32 = MARGIN;
+const RIGHT_COLUMN: f32 = MARGIN + COLUMN_WIDTH + COLUMN_GAP;
+const RIGHT_EDGE: f32 = PAGE_WIDTH - MARGIN;
+const BLOCK_SPACING: f32 = 5.0;
+const CELL_PADDING: f32 = 2.0;
+const QR_SIZE: f32 = 50.0;
+
+const TEXT_COLOR: f32 = 0.0;
+const GRAYED_COLOR: f32 = 0.33;
+const LINE_COLOR: f32 = 0.73;
+
+/// Errors that can occur while rendering the PDF
+#[derive(Debug)]
+pub enum Error {
+    PdfError(printpdf::Error),
+    FontError(ttf_parser::FaceParsingError),
+    PaymentCodeError(payment_code::Error),
+    IoError(std::io::Error),
+    /// The saved document has no single file identifier in the trailer
+    TrailerError,
+}
+
+impl Display for Error {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Error::PdfError(e) => {
+                write!(
+                    f,
+                    "PDF error: {}",
+                    e
+                )
+            }
+            Error::FontError(e) => {
+                write!(
+                    f,
+                    "Font error: {}",
+                    e
+                )
+            }
+            Error::PaymentCodeError(e) => {
+                write!(
+                    f,
+                    "Payment code error: {}",
+                    e
+                )
+            }
+            Error::IoError(e) => {
+                write!(
+                    f,
+                    "IO error: {}",
+                    e
+                )
+            }
+            Error::TrailerError => {
+                write!(
+                    f,
+                    "PDF error: the trailer doesn't contain exactly one file identifier"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Clone, Copy)]
+struct Style {
+    size: f32,
+    bold: bool,
+    color: f32,
+}
+
+const NORMAL: Style = Style {
+    size: 9.0,
+    bold: false,
+    color: TEXT_COLOR,
+};
+
+const GRAYED: Style = Style {
+    size: 9.0,
+    bold: false,
+    color: GRAYED_COLOR,
+};
+
+const BOLD: Style = Style {
+    size: 9.0,
+    bold: true,
+    color: TEXT_COLOR,
+};
+
+const HEADING: Style = Style {
+    size: 11.0,
+    bold: true,
+    color: TEXT_COLOR,
+};
+
+const TITLE: Style = Style {
+    size: 20.0,
+    bold: true,
+    color: TEXT_COLOR,
+};
+
+const TOTAL: Style = Style {
+    size: 13.5,
+    bold: true,
+    color: TEXT_COLOR,
+};
+
+impl Style {
+    /// Height of a single line of text in millimeters
+    fn line_height(&self) -> f32 {
+        self.size * 1.5 * PT_TO_MM
+    }
+}
+
+struct Font {
+    reference: IndirectFontRef,
+    face: Face<'static>,
+    /// Glyphs of the text written with the font
+    glyphs: RefCell<BTreeSet<u16>>,
+}
+
+impl Font {
+    /// Measures the text with `data` and embeds `embedded`, a subset of it, into the document
+    fn new(
+        document: &PdfDocumentReference,
+        data: &'static [u8],
+        embedded: &[u8],
+    ) -> Result<Self, Error> {
+        Ok(
+            Self {
+                reference: document
+                    .add_external_font(embedded)
+                    .map_err(Error::PdfError)?,
+                face: Face::parse(
+                    data, 0,
+                )
+                .map_err(Error::FontError)?,
+                glyphs: RefCell::new(BTreeSet::new()),
+            },
+        )
+    }
+
+    /// Width of the text in millimeters
+    fn width(
+        &self,
+        text: &str,
+        size: f32,
+    ) -> f32 {
+        let units: u32 = text
+            .chars()
+            .filter_map(
+                |c| {
+                    self.face
+                        .glyph_index(c)
+                },
+            )
+            .filter_map(
+                |glyph| {
+                    self.face
+                        .glyph_hor_advance(glyph)
+                },
+            )
+            .map(u32::from)
+            .sum();
+
+        units as f32
+            / self
+                .face
+                .units_per_em() as f32
+            * size
+            * PT_TO_MM
+    }
+}
+
+/// Tables only used to shape text, PDF viewers draw the glyphs as positioned by the document
+const SHAPING_TABLES: [&[u8; 4]; 3] = [
+    b"GDEF", b"GPOS", b"GSUB",
+];
+
+fn read_u16(
+    data: &[u8],
+    offset: usize,
+) -> Option<u16> {
+    data.get(offset..offset + 2)
+        .map(
+            |x| {
+                u16::from_be_bytes(
+                    [
+                        x[0], x[1],
+                    ],
+                )
+            },
+        )
+}
+
+fn read_u32(
+    data: &[u8],
+    offset: usize,
+) -> Option<u32> {
+    data.get(offset..offset + 4)
+        .map(
+            |x| {
+                u32::from_be_bytes(
+                    [
+                        x[0], x[1], x[2], x[3],
+                    ],
+                )
+            },
+        )
+}
+
+/// Sum of the big endian words of the table, the table is padded with zeros
+fn table_checksum(table: &[u8]) -> u32 {
+    table
+        .chunks(4)
+        .map(
+            |x| {
+                let mut word = [0; 4];
+                word[..x.len()].copy_from_slice(x);
+                u32::from_be_bytes(word)
+            },
+        )
+        .fold(
+            0,
+            u32::wrapping_add,
+        )
+}
+
+/// Glyphs a composite glyph is made of, simple glyphs have none
+fn glyph_components(outline: &[u8]) -> Vec<u16> {
+    const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+    const WE_HAVE_A_SCALE: u16 = 0x0008;
+    const MORE_COMPONENTS: u16 = 0x0020;
+    const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+    const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+
+    let mut components = Vec::new();
+
+    // Composite glyphs have a negative number of contours
+    let Some(contours) = read_u16(
+        outline, 0,
+    ) else {
+        return components;
+    };
+
+    if (contours as i16) >= 0 {
+        return components;
+    }
+
+    let mut offset = 10;
+
+    while let (Some(flags), Some(glyph)) = (
+        read_u16(
+            outline, offset,
+        ),
+        read_u16(
+            outline,
+            offset + 2,
+        ),
+    ) {
+        components.push(glyph);
+
+        offset += 4;
+        offset += if flags & ARG_1_AND_2_ARE_WORDS != 0 {
+            4
+        } else {
+            2
+        };
+
+        if flags & WE_HAVE_A_SCALE != 0 {
+            offset += 2;
+        } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+            offset += 4;
+        } else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+            offset += 8;
+        }
+
+        if flags & MORE_COMPONENTS == 0 {
+            break;
+        }
+    }
+
+    components
+}
+
+/// Returns the font with the outlines of the `glyphs` only.
+///
+/// The other glyphs stay in the font without an outline, so glyph ids and metrics don't change.
+/// Glyph names and the tables used to shape text are left out.
+fn subset_font(
+    data: &[u8],
+    glyphs: &BTreeSet<u16>,
+) -> Result<Vec<u8>, Error> {
+    let malformed = || Error::FontError(FaceParsingError::MalformedFont);
+
+    let face = RawFace::parse(
+        data, 0,
+    )
+    .map_err(Error::FontError)?;
+    let table = |tag: &[u8; 4]| {
+        face.table(Tag::from_bytes(tag))
+            .ok_or_else(malformed)
+    };
+
+    let head = table(b"head")?;
+    let loca = table(b"loca")?;
+    let glyf = table(b"glyf")?;
+    let glyph_count = read_u16(
+        table(b"maxp")?,
+        4,
+    )
+    .ok_or_else(malformed)?;
+    let long_offsets = read_u16(
+        head, 50,
+    )
+    .ok_or_else(malformed)?
+        == 1;
+
+    let outline = |glyph: u16| {
+        let glyph = glyph as usize;
+        let (start, end) = if long_offsets {
+            (
+                read_u32(
+                    loca,
+                    glyph * 4,
+                )
+                .map(|x| x as usize),
+                read_u32(
+                    loca,
+                    glyph * 4 + 4,
+                )
+                .map(|x| x as usize),
+            )
+        } else {
+            (
+                read_u16(
+                    loca,
+                    glyph * 2,
+                )
+                .map(|x| x as usize * 2),
+                read_u16(
+                    loca,
+                    glyph * 2 + 2,
+                )
+                .map(|x| x as usize * 2),
+            )
+        };
+
+        start
+            .zip(end)
+            .and_then(|(start, end)| glyf.get(start..end))
+            .ok_or_else(malformed)
+    };
+
+    // .notdef is drawn for characters missing in the font
+    let mut used = BTreeSet::from([0]);
+    let mut pending: Vec<u16> = glyphs
+        .iter()
+        .copied()
+        .chain([0])
+        .collect();
+
+    while let Some(glyph) = pending.pop() {
+        used.insert(glyph);
+
+        for component in glyph_components(outline(glyph)?) {
+            if !used.contains(&component) {
+                pending.push(component);
+            }
+        }
+    }
+
+    let mut new_glyf = Vec::new();
+    let mut new_loca = Vec::new();
+
+    for glyph in 0..glyph_count {
+        new_loca.extend((new_glyf.len() as u32).to_be_bytes());
+
+        if used.contains(&glyph) {
+            new_glyf.extend(outline(glyph)?);
+            new_glyf.resize(
+                new_glyf
+                    .len()
+                    .next_multiple_of(4),
+                0,
+            );
+        }
+    }
+
+    new_loca.extend((new_glyf.len() as u32).to_be_bytes());
+
+    let mut new_head = head.to_vec();
+    // checkSumAdjustment is computed below, the offsets in loca are 32 bit
+    new_head[8..12].fill(0);
+    new_head[50..52].copy_from_slice(&1u16.to_be_bytes());
+
+    // Version 3 of the post table has no glyph names
+    let mut post = table(b"post")?
+        .get(..32)
+        .ok_or_else(malformed)?
+        .to_vec();
+    post[..4].copy_from_slice(&0x0003_0000u32.to_be_bytes());
+
+    let mut tables: Vec<(
+        Tag,
+        Vec<u8>,
+    )> = Vec::new();
+
+    for record in face.table_records {
+        if SHAPING_TABLES.contains(
+            &&record
+                .tag
+                .to_bytes(),
+        ) {
+            continue;
+        }
+
+        let content = match &record
+            .tag
+            .to_bytes()
+        {
+            b"head" => new_head.clone(),
+            b"loca" => new_loca.clone(),
+            b"glyf" => new_glyf.clone(),
+            b"post" => post.clone(),
+            _ => {
+                face.table(record.tag)
+                    .ok_or_else(malformed)?
+                    .to_vec()
+            }
+        };
+
+        tables.push(
+            (
+                record.tag, content,
+            ),
+        );
+    }
+
+    let count = tables.len() as u16;
+    let entry_selector = count.ilog2() as u16;
+    let search_range: u16 = 16 << entry_selector;
+
+    let mut font = Vec::new();
+    font.extend(
+        data.get(..4)
+            .ok_or_else(malformed)?,
+    );
+    font.extend(count.to_be_bytes());
+    font.extend(search_range.to_be_bytes());
+    font.extend(entry_selector.to_be_bytes());
+    font.extend((count * 16 - search_range).to_be_bytes());
+
+    let mut offset = font.len() + tables.len() * 16;
+    let mut head_offset = 0;
+
+    for (tag, content) in &tables {
+        if tag.to_bytes() == *b"head" {
+            head_offset = offset;
+        }
+
+        font.extend(tag.to_bytes());
+        font.extend(table_checksum(content).to_be_bytes());
+        font.extend((offset as u32).to_be_bytes());
+        font.extend((content.len() as u32).to_be_bytes());
+        offset += content
+            .len()
+            .next_multiple_of(4);
+    }
+
+    for (_, content) in &tables {
+        font.extend(content);
+        font.resize(
+            font.len()
+                .next_multiple_of(4),
+            0,
+        );
+    }
+
+    let adjustment = 0xB1B0_AFBAu32.wrapping_sub(table_checksum(&font));
+    font[head_offset + 8..head_offset + 12].copy_from_slice(&adjustment.to_be_bytes());
+
+    Ok(font)
+}
+
+fn gray(value: f32) -> Color {
+    Color::Greyscale(
+        Greyscale::new(
+            value, None,
+        ),
+    )
+}
+
+/// Draws on the pages of a PDF document.
+///
+/// All coordinates are in millimeters, measured from the top left corner of the page.
+struct Writer {
+    /// Identifier of the document, the same title gives the same identifier
+    id: String,
+    document: PdfDocumentReference,
+    layer: PdfLayerReference,
+    regular: Font,
+    bold: Font,
+    y: f32,
+}
+
+impl Writer {
+    fn new(
+        title: &str,
+        date: OffsetDateTime,
+        regular: &[u8],
+        bold: &[u8],
+    ) -> Result<Self, Error> {
+        let (document, page, layer) = PdfDocument::new(
+            title,
+            Mm(PAGE_WIDTH),
+            Mm(PAGE_HEIGHT),
+            "Layer 1",
+        );
+
+        let id = Uuid::new_v5(
+            &Uuid::NAMESPACE_OID,
+            title.as_bytes(),
+        )
+        .simple()
+        .to_string()
+        .to_uppercase();
+
+        let document = document
+            .with_creation_date(date)
+            .with_mod_date(date)
+            .with_metadata_date(date)
+            .with_document_id(id.clone());
+
+        let layer = document
+            .get_page(page)
+            .get_layer(layer);
+
+        Ok(
+            Self {
+                regular: Font::new(
+                    &document,
+                    REGULAR_FONT,
+                    regular,
+                )?,
+                bold: Font::new(
+                    &document, BOLD_FONT, bold,
+                )?,
+                id,
+                document,
+                layer,
+                y: MARGIN,
+            },
+        )
+    }
+
+    /// Saves the document.
+    ///
+    /// printpdf fills the file identifier in the trailer with random characters,
+    /// it's replaced by the identifier derived from the title so the same invoice always gives the same bytes.
+    fn finish(self) -> Result<Vec<u8>, Error> {
+        let mut bytes = self
+            .document
+            .save_to_bytes()
+            .map_err(Error::PdfError)?;
+
+        // Both identifiers are 32 characters long, so the offsets in the file don't change
+        let id = format!(
+            "/ID[({})({})]",
+            self.id, self.id
+        );
+        let separator = "/ID[(".len()
+            + self
+                .id
+                .len();
+
+        let trailer_ids: Vec<usize> = bytes
+            .windows(id.len())
+            .enumerate()
+            .filter(
+                |(_, x)| {
+                    x.starts_with(b"/ID[(")
+                        && x[separator..].starts_with(b")(")
+                        && x.ends_with(b")]")
+                },
+            )
+            .map(|(position, _)| position)
+            .collect();
+
+        let [trailer_id] = trailer_ids[..] else {
+            return Err(Error::TrailerError);
+        };
+
+        bytes[trailer_id..trailer_id + id.len()].copy_from_slice(id.as_bytes());
+
+        Ok(bytes)
+    }
+
+    /// Continues on a new page if less than `height` is left on the current one
+    fn ensure_space(
+        &mut self,
+        height: f32,
+    ) {
+        if self.y + height <= PAGE_HEIGHT - MARGIN {
+            return;
+        }
+
+        let (page, layer) = self
+            .document
+            .add_page(
+                Mm(PAGE_WIDTH),
+                Mm(PAGE_HEIGHT),
+                "Layer 1",
+            );
+
+        self.layer = self
+            .document
+            .get_page(page)
+            .get_layer(layer);
+        self.y = MARGIN;
+    }
+
+    fn font(
+        &self,
+        style: Style,
+    ) -> &Font {
+        if style.bold {
+            &self.bold
+        } else {
+            &self.regular
+        }
+    }
+
+    fn width(
+        &self,
+        text: &str,
+        style: Style,
+    ) -> f32 {
+        self.font(style)
+            .width(
+                text, style.size,
+            )
+    }
+
+    /// Writes the text with its left edge at `x` into the line starting at `y`
+    fn text(
+        &self,
+        text: &str,
+        x: f32,
+        y: f32,
+        style: Style,
+    ) {
+        let baseline = y + style.line_height() * 0.7;
+        let font = self.font(style);
+
+        font.glyphs
+            .borrow_mut()
+            .extend(
+                text.chars()
+                    .filter_map(
+                        |c| {
+                            font.face
+                                .glyph_index(c)
+                        },
+                    )
+                    .map(|glyph| glyph.0),
+            );
+
+        self.layer
+            .set_fill_color(gray(style.color));
+        self.layer
+            .use_text(
+                text,
+                style.size,
+                Mm(x),
+                Mm(PAGE_HEIGHT - baseline),
+                &font.reference,
+            );
+    }
+
+    /// Writes the text with its right edge at `x` into the line starting at `y`
+    fn text_right(
+        &self,
+        text: &str,
+        x: f32,
+        y: f32,
+        style: Style,
+    ) {
+        self.text(
+            text,
+            x - self.width(
+                text, style,
+            ),
+            y,
+            style,
+        );
+    }
+
+    /// Draws a horizontal line of the given thickness in pixels
+    fn line(
+        &self,
+        from: f32,
+        to: f32,
+        y: f32,
+        thickness: f32,
+        color: f32,
+    ) {
+        self.layer
+            .set_outline_color(gray(color));
+        self.layer
+            .set_outline_thickness(thickness * PX_TO_PT);
+        self.layer
+            .add_line(
+                Line {
+                    points: vec![
+                        (
+                            Point::new(
+                                Mm(from),
+                                Mm(PAGE_HEIGHT - y),
+                            ),
+                            false,
+                        ),
+                        (
+                            Point::new(
+                                Mm(to),
+                                Mm(PAGE_HEIGHT - y),
+                            ),
+                            false,
+                        ),
+                    ],
+                    is_closed: false,
+                },
+            );
+    }
+
+    fn rect(
+        &self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+    ) {
+        self.layer
+            .set_fill_color(gray(TEXT_COLOR));
+        self.layer
+            .add_rect(
+                Rect::new(
+                    Mm(x),
+                    Mm(PAGE_HEIGHT - y - height),
+                    Mm(x + width),
+                    Mm(PAGE_HEIGHT - y),
+                ),
+            );
+    }
+
+    /// Splits the text into lines that fit into `width`
+    fn wrap(
+        &self,
+        text: &str,
+        width: f32,
+        style: Style,
+    ) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut current = String::new();
+
+        for word in text.split_whitespace() {
+            let candidate = if current.is_empty() {
+                word.to_string()
+            } else {
+                format!(
+                    "{} {}",
+                    current, word
+                )
+            };
+
+            if !current.is_empty()
+                && self.width(
+                    &candidate, style,
+                ) > width
+            {
+                lines.push(
+                    std::mem::replace(
+                        &mut current,
+                        word.to_string(),
+                    ),
+                );
+            } else {
+                current = candidate;
+            }
+        }
+
+        if !current.is_empty() {
+            lines.push(current);
+        }
+
+        lines
+    }
+
+    /// Writes a grayed label and a right aligned value, returns the start of the next line
+    fn row(
+        &self,
+        label: &str,
+        value: &str,
+        x: f32,
+        y: f32,
+    ) -> f32 {
+        self.text(
+            label, x, y, GRAYED,
+        );
+        self.text_right(
+            value,
+            x + COLUMN_WIDTH,
+            y,
+            NORMAL,
+        );
+
+        y + NORMAL.line_height()
+    }
+
+    /// Writes the entity block, returns the position below it
+    fn entity(
+        &self,
+        title: &str,
+        entity: &Entity,
+        show_country: bool,
+        x: f32,
+        y: f32,
+    ) -> f32 {
+        self.text(
+            title, x, y, HEADING,
+        );
+        let mut y = y + HEADING.line_height() + BLOCK_SPACING;
+
+        self.text(
+            entity.name(),
+            x,
+            y,
+            BOLD,
+        );
+        y += BOLD.line_height() + BLOCK_SPACING;
+
+        for line in entity.address_lines(show_country) {
+            self.text(
+                &line, x, y, GRAYED,
+            );
+            y += GRAYED.line_height();
+        }
+        y += BLOCK_SPACING;
+
+        let entity = match entity {
+            Entity::Eu(entity) => entity,
+            Entity::Us(entity) => {
+                return self.row(
+                    entity
+                        .tax_id
+                        .label(),
+                    entity
+                        .tax_id
+                        .get(),
+                    x,
+                    y,
+                );
+            }
+        };
+
+        y = self.row(
+            entity
+                .identifier
+                .label(),
+            entity
+                .identifier
+                .get(),
+            x,
+            y,
+        );
+
+        match &entity.vat_number {
+            Some(vat_number) => {
+                self.row(
+                    "DPH",
+                    &vat_number.to_string(),
+                    x,
+                    y,
+                )
+            }
+            None => {
+                self.text(
+                    "Neplátce DPH",
+                    x,
+                    y,
+                    NORMAL,
+                );
+                y + NORMAL.line_height()
+            }
+        }
+    }
+
+    /// Writes a table row of `(width, text)` columns and moves below it.
+    /// The second column is wrapped and left aligned, the others are right aligned.
+    fn table_row(
+        &mut self,
+        columns: &[(
+            f32,
+            String,
+        )],
+        style: Style,
+    ) {
+        let mut x = MARGIN;
+        let mut cells = Vec::new();
+
+        for (index, (width, text)) in columns
+            .iter()
+            .enumerate()
+        {
+            let lines = if index == 1 {
+                self.wrap(
+                    text,
+                    width - 2.0 * CELL_PADDING,
+                    style,
+                )
+            } else {
+                vec![text.to_string()]
+            };
+
+            cells.push(
+                (
+                    x,
+                    *width,
+                    index == 1,
+                    lines,
+                ),
+            );
+            x += width;
+        }
+
+        let height = cells
+            .iter()
+            .map(|(_, _, _, lines)| lines.len())
+            .max()
+            .unwrap_or(1)
+            .max(1) as f32
+            * style.line_height()
+            + 2.0 * CELL_PADDING;
+
+        self.ensure_space(height);
+
+        for (x, width, left_aligned, lines) in &cells {
+            let mut y = self.y + CELL_PADDING;
+
+            for line in lines {
+                if *left_aligned {
+                    self.text(
+                        line,
+                        x + CELL_PADDING,
+                        y,
+                        style,
+                    );
+                } else {
+                    self.text_right(
+                        line,
+                        x + width - CELL_PADDING,
+                        y,
+                        style,
+                    );
+                }
+
+                y += style.line_height();
+            }
+        }
+
+        self.y += height;
+    }
+
+    /// Draws the QR code into a square of `size` with its top left corner at `x` and `y`
+    fn qr_code(
+        &self,
+        qr: &qr::QRCode,
+        x: f32,
+        y: f32,
+        size: f32,
+    ) {
+        let module = size / qr.size as f32;
+
+        for row in 0..qr.size {
+            for column in 0..qr.size {
+                if qr[row][column].value() {
+                    self.rect(
+                        x + column as f32 * module,
+                        y + row as f32 * module,
+                        module,
+                        module,
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl Invoice {
+    /// Renders the invoice as a PDF document.
+    ///
+    /// The invoice is drawn twice, only the glyphs written by the first drawing are embedded into the document.
+    pub fn to_pdf_bytes(&self) -> Result<Vec<u8>, Error> {
+        let draft = self.draw(
+            REGULAR_FONT,
+            BOLD_FONT,
+        )?;
+
+        let regular = subset_font(
+            REGULAR_FONT,
+            &draft
+                .regular
+                .glyphs
+                .borrow(),
+        )?;
+        let bold = subset_font(
+            BOLD_FONT,
+            &draft
+                .bold
+                .glyphs
+                .borrow(),
+        )?;
+
+        self.draw(
+            &regular, &bold,
+        )?
+        .finish()
+    }
+
+    /// Draws the invoice, the fonts are the ones embedded into the document
+    fn draw(
+        &self,
+        regular: &[u8],
+        bold: &[u8],
+    ) -> Result<Writer, Error> {
+        let ac = accounting::create_accounting_from_currency(self.currency);
+        let title = format!(
+            "{} {}",
+            self.title(),
+            self.number
+        );
+
+        let date = OffsetDateTime::from_unix_timestamp(
+            self.date
+                .and_hms_opt(
+                    0, 0, 0,
+                )
+                .map(
+                    |x| {
+                        x.and_utc()
+                            .timestamp()
+                    },
+                )
+                .unwrap_or_default(),
+        )
+        .unwrap_or(OffsetDateTime::UNIX_EPOCH);
+
+        let mut writer = Writer::new(
+            &title, date, regular, bold,
+        )?;
+
+        writer.line(
+            RIGHT_COLUMN,
+            RIGHT_EDGE,
+            writer.y,
+            4.0,
+            LINE_COLOR,
+        );
+        writer.y += 1.0;
+
+        for line in writer.wrap(
+            &title,
+            COLUMN_WIDTH,
+            TITLE,
+        ) {
+            writer.text(
+                &line,
+                RIGHT_COLUMN,
+                writer.y,
+                TITLE,
+            );
+            writer.y += TITLE.line_height();
+        }
+
+        writer.y += BLOCK_SPACING;
+
+        let show_country = self.shows_country();
+        let contractor_end = writer.entity(
+            "DODAVATEL",
+            &self.contractor,
+            show_country,
+            LEFT_COLUMN,
+            writer.y,
+        );
+        let client_end = writer.entity(
+            "ODBĚRATEL",
+            &self.client,
+            show_country,
+            RIGHT_COLUMN,
+            writer.y,
+        );
+        writer.y = contractor_end.max(client_end) + BLOCK_SPACING;
+
+        let mut payment_end = writer.y;
+
+        if let Some(iban) = self.iban() {
+            let bank_account = iban.to_bank_account_number();
+            let iban = iban.to_string();
+
+            payment_end = writer.row(
+                "Bankovní účet",
+                &bank_account,
+                LEFT_COLUMN,
+                payment_end,
+            );
+
+            if bank_account != iban {
+                payment_end = writer.row(
+                    "IBAN",
+                    &iban,
+                    LEFT_COLUMN,
+                    payment_end,
+                );
+            }
+        }
+
+        if let Some(bank) = self.bank() {
+            payment_end = writer.row(
+                "Banka",
+                &bank.name,
+                LEFT_COLUMN,
+                payment_end,
+            );
+        }
+
+        if let Some(bic) = self.bic() {
+            payment_end = writer.row(
+                "SWIFT/BIC",
+                &bic,
+                LEFT_COLUMN,
+                payment_end,
+            );
+        }
+
+        if let PaymentMethod::BankTransfer(var_symbol) = &self.payment_method {
+            payment_end = writer.row(
+                "Variabilní symbol",
+                var_symbol,
+                LEFT_COLUMN,
+                payment_end,
+            );
+        }
+
+        if let Some(constant_symbol) = &self.constant_symbol {
+            payment_end = writer.row(
+                "Konstantní symbol",
+                constant_symbol,
+                LEFT_COLUMN,
+                payment_end,
+            );
+        }
+
+        if let Some(specific_symbol) = &self.specific_symbol {
+            payment_end = writer.row(
+                "Specifický symbol",
+                specific_symbol,
+                LEFT_COLUMN,
+                payment_end,
+            );
+        }
+
+        payment_end = writer.row(
+            "Způsob platby",
+            self.payment_method
+                .name(),
+            LEFT_COLUMN,
+            payment_end,
+        );
+
+        let mut dates_end = writer.row(
+            "Datum vystavení",
+            &self
+                .date
+                .format(DATE_FORMAT)
+                .to_string(),
+            RIGHT_COLUMN,
+            writer.y,
+        );
+
+        dates_end = writer.row(
+            "Datum splatnosti",
+            &self
+                .due_date
+                .format(DATE_FORMAT)
+                .to_string(),
+            RIGHT_COLUMN,
+            dates_end,
+        );
+
+        if let Some(correction) = self.correction() {
+            dates_end = writer.row(
+                "Opravovaný doklad",
+                &correction
+                    .original_number
+                    .to_string(),
+                RIGHT_COLUMN,
+                dates_end,
+            );
+
+            dates_end = writer.row(
+                "Ze dne",
+                &correction
+                    .original_date
+                    .format(DATE_FORMAT)
+                    .to_string(),
+                RIGHT_COLUMN,
+                dates_end,
+            );
+        }
+
+        writer.y = payment_end.max(dates_end) + BLOCK_SPACING;
+
+        if let Some(correction) = self.correction() {
+            let label = "Důvod opravy: ";
+            let label_width = writer.width(
+                label, GRAYED,
+            );
+
+            writer.text(
+                label, MARGIN, writer.y, GRAYED,
+            );
+
+            for line in writer.wrap(
+                &correction.reason,
+                RIGHT_EDGE - MARGIN - label_width,
+                NORMAL,
+            ) {
+                writer.text(
+                    &line,
+                    MARGIN + label_width,
+                    writer.y,
+                    NORMAL,
+                );
+                writer.y += NORMAL.line_height();
+            }
+
+            writer.y += BLOCK_SPACING;
+        }
+
+        let vat_payer = self
+            .contractor
+            .is_vat_payer();
+
+        let quantity_width = 22.0;
+        let price_width = 32.0;
+        let vat_width = if vat_payer {
+            20.0
+        } else {
+            0.0
+        };
+        let description_width =
+            RIGHT_EDGE - MARGIN - quantity_width - 2.0 * price_width - vat_width;
+
+        let columns =
+            |quantity: String, description: String, price: String, vat: String, total: String| {
+                let mut columns = vec![
+                    (
+                        quantity_width,
+                        quantity,
+                    ),
+                    (
+                        description_width,
+                        description,
+                    ),
+                    (
+                        price_width,
+                        price,
+                    ),
+                ];
+
+                if vat_payer {
+                    columns.push(
+                        (
+                            vat_width, vat,
+                        ),
+                    );
+                }
+
+                columns.push(
+                    (
+                        price_width,
+                        total,
+                    ),
+                );
+
+                columns
+            };
+
+        let header = columns(
+            String::new(),
+            String::new(),
+            "CENA ZA MJ".to_string(),
+            "DPH".to_string(),
+            if vat_payer {
+                "CELKEM BEZ DPH".to_string()
+            } else {
+                "CELKEM".to_string()
+            },
+        );
+
+        writer.table_row(
+            &header, BOLD,
+        );
+        writer.line(
+            MARGIN, RIGHT_EDGE, writer.y, 2.0, LINE_COLOR,
+        );
+
+        for item in &self.items {
+            let vat_rate = self.vat_rate(item);
+            let total = if vat_payer {
+                item.price_without_vat(vat_rate)
+            } else {
+                item.price()
+            };
+
+            let row = columns(
+                item.quantity(),
+                item.description
+                    .clone(),
+                ac.format_money(item.price_per_unit),
+                vat_rate.to_string(),
+                ac.format_money(total),
+            );
+
+            writer.table_row(
+                &row, NORMAL,
+            );
+        }
+
+        writer.line(
+            MARGIN, RIGHT_EDGE, writer.y, 2.0, LINE_COLOR,
+        );
+        writer.y += BLOCK_SPACING;
+
+        if self.is_tax_document() {
+            let recapitulation_width = (RIGHT_EDGE - MARGIN) / 4.0;
+
+            let header = [
+                "REKAPITULACE DPH",
+                "ZÁKLAD",
+                "DPH",
+                "CELKEM",
+            ];
+
+            writer.ensure_space(2.0 * BOLD.line_height());
+
+            for (index, text) in header
+                .iter()
+                .enumerate()
+            {
+                let x = MARGIN + index as f32 * recapitulation_width;
+
+                if index == 0 {
+                    writer.text(
+                        text,
+                        x + CELL_PADDING,
+                        writer.y + CELL_PADDING,
+                        BOLD,
+                    );
+                } else {
+                    writer.text_right(
+                        text,
+                        x + recapitulation_width - CELL_PADDING,
+                        writer.y + CELL_PADDING,
+                        BOLD,
+                    );
+                }
+            }
+
+            writer.y += BOLD.line_height() + 2.0 * CELL_PADDING;
+            writer.line(
+                MARGIN, RIGHT_EDGE, writer.y, 2.0, LINE_COLOR,
+            );
+
+            for summary in self.vat_recapitulation() {
+                writer.ensure_space(NORMAL.line_height() + 2.0 * CELL_PADDING);
+
+                writer.text(
+                    &summary
+                        .rate
+                        .to_string(),
+                    MARGIN + CELL_PADDING,
+                    writer.y + CELL_PADDING,
+                    NORMAL,
+                );
+
+                for (index, amount) in [
+                    summary.base,
+                    summary.vat,
+                    summary.total,
+                ]
+                .into_iter()
+                .enumerate()
+                {
+                    writer.text_right(
+                        &ac.format_money(amount),
+                        MARGIN + (index + 2) as f32 * recapitulation_width - CELL_PADDING,
+                        writer.y + CELL_PADDING,
+                        NORMAL,
+                    );
+                }
+
+                writer.y += NORMAL.line_height() + 2.0 * CELL_PADDING;
+            }
+
+            writer.line(
+                MARGIN, RIGHT_EDGE, writer.y, 2.0, LINE_COLOR,
+            );
+            writer.y += BLOCK_SPACING;
+        }
+
+        let balance = self.balance();
+
+        if !self
+            .advances
+            .is_empty()
+        {
+            writer.ensure_space(
+                (self
+                    .advances
+                    .len()
+                    + 1) as f32
+                    * NORMAL.line_height(),
+            );
+
+            writer.text(
+                "Celkem",
+                MARGIN + CELL_PADDING,
+                writer.y,
+                NORMAL,
+            );
+            writer.text_right(
+                &ac.format_money(self.total()),
+                RIGHT_EDGE - CELL_PADDING,
+                writer.y,
+                NORMAL,
+            );
+            writer.y += NORMAL.line_height();
+
+            for advance in &self.advances {
+                writer.text(
+                    &format!(
+                        "Uhrazená záloha č. {}",
+                        advance.number
+                    ),
+                    MARGIN + CELL_PADDING,
+                    writer.y,
+                    NORMAL,
+                );
+                writer.text_right(
+                    &ac.format_money(-advance.amount),
+                    RIGHT_EDGE - CELL_PADDING,
+                    writer.y,
+                    NORMAL,
+                );
+                writer.y += NORMAL.line_height();
+            }
+
+            writer.y += CELL_PADDING;
+            writer.line(
+                MARGIN, RIGHT_EDGE, writer.y, 2.0, LINE_COLOR,
+            );
+            writer.y += BLOCK_SPACING;
+        }
+
+        writer.ensure_space(QR_SIZE);
+
+        if let Some(qr) = self
+            .qr(&balance)
+            .map_err(Error::PaymentCodeError)?
+        {
+            writer.qr_code(
+                &qr,
+                LEFT_COLUMN,
+                writer.y,
+                QR_SIZE,
+            );
+        }
+
+        writer.line(
+            RIGHT_COLUMN,
+            RIGHT_EDGE,
+            writer.y,
+            4.0,
+            TEXT_COLOR,
+        );
+        writer.text_right(
+            &ac.format_money(balance),
+            RIGHT_EDGE,
+            writer.y + 1.0,
+            TOTAL,
+        );
+        writer.y += QR_SIZE + BLOCK_SPACING;
+
+        if self.kind == InvoiceKind::Advance {
+            writer.text(
+                "Tento doklad není daňovým dokladem.",
+                MARGIN,
+                writer.y,
+                GRAYED,
+            );
+            writer.y += NORMAL.line_height();
+        }
+
+        if let Some(note) = &self.note {
+            let lines = writer.wrap(
+                note,
+                RIGHT_EDGE - MARGIN,
+                NORMAL,
+            );
+            let height = lines.len() as f32 * NORMAL.line_height();
+
+            writer.ensure_space(height);
+
+            let mut y = PAGE_HEIGHT - MARGIN - height;
+
+            for line in &lines {
+                writer.text(
+                    line, MARGIN, y, NORMAL,
+                );
+                y += NORMAL.line_height();
+            }
+        }
+
+        Ok(writer)
+    }
+
+    /// Renders the invoice as a PDF document and writes it into `filename`.
+    pub fn to_pdf(
+        &self,
+        filename: &str,
+    ) -> Result<(), Error> {
+        std::fs::write(
+            filename,
+            self.to_pdf_bytes()?,
+        )
+        .map_err(Error::IoError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iso_currency::Currency;
+
+    use super::*;
+    use crate::fixtures::entity;
+    use crate::fixtures::iban;
+    use crate::fixtures::invoice;
+
+    #[test]
+    fn test_pdf_bytes() {
+        let invoice = invoice(
+            entity("27082440"),
+            entity("25596641"),
+            Some(iban()),
+            Currency::CZK,
+        );
+
+        let pdf = invoice
+            .to_pdf_bytes()
+            .unwrap();
+
+        assert!(pdf.starts_with(b"%PDF"));
+        // Compared without assert_eq, which would print both documents
+        assert!(
+            pdf == invoice
+                .to_pdf_bytes()
+                .unwrap()
+        );
+
+        // Only the used glyphs are embedded, the whole document is smaller than one of the fonts
+        assert!(pdf.len() < REGULAR_FONT.len());
+    }
+
+    #[test]
+    fn test_subset_font() {
+        let face = Face::parse(
+            REGULAR_FONT,
+            0,
+        )
+        .unwrap();
+        let glyph = |face: &Face, c: char| {
+            face.glyph_index(c)
+                .unwrap()
+        };
+
+        let subset = subset_font(
+            REGULAR_FONT,
+            &BTreeSet::from(
+                [
+                    glyph(
+                        &face, 'a',
+                    )
+                    .0,
+                    glyph(
+                        &face, 'ř',
+                    )
+                    .0,
+                ],
+            ),
+        )
+        .unwrap();
+        let subset_face = Face::parse(
+            &subset, 0,
+        )
+        .unwrap();
+
+        assert!(subset.len() < REGULAR_FONT.len() / 5);
+        assert_eq!(
+            subset_face.number_of_glyphs(),
+            face.number_of_glyphs()
+        );
+
+        for c in [
+            'a', 'ř', 'r',
+        ] {
+            assert!(
+                subset_face
+                    .glyph_bounding_box(glyph(&face, c))
+                    .is_some(),
+                "{} has no outline",
+                c
+            );
+        }
+
+        assert!(
+            subset_face
+                .glyph_bounding_box(glyph(&face, 'x'))
+                .is_none()
+        );
+        assert_eq!(
+            subset_face.glyph_hor_advance(glyph(&face, 'x')),
+            face.glyph_hor_advance(glyph(&face, 'x'))
+        );
+    }
+}
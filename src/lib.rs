@@ -1,15 +1,57 @@
+#[cfg(
+    all(
+        any(
+            feature = "rpo",
+            feature = "vies",
+            feature = "adis"
+        ),
+        not(
+            any(
+                feature = "blocking",
+                feature = "async"
+            )
+        )
+    )
+)]
+compile_error!(
+    "The rpo, vies and adis features need a transport, enable the blocking or the async feature"
+);
+
 mod accounting;
 pub mod address;
+#[cfg(feature = "adis")]
+pub mod adis;
+#[cfg(feature = "ares")]
 pub mod ares;
+pub mod bank_account;
+pub mod bank_code;
+pub mod country;
 pub mod entity;
+#[cfg(test)]
+mod fixtures;
+#[cfg(
+    any(
+        feature = "blocking",
+        feature = "async"
+    )
+)]
+mod http;
 pub mod invoice;
 pub mod payment_method;
 pub mod registration_number;
+pub mod registry;
+#[cfg(feature = "rpo")]
+pub mod rpo;
 pub mod time;
+pub mod vat;
+pub mod vat_number;
+#[cfg(feature = "vies")]
+pub mod vies;
 
 pub use invoice::Invoice;
 pub use invoice::InvoiceItem;
 pub use invoice::InvoiceItemType;
+pub use invoice::InvoiceKind;
 
 pub use entity::Entity;
 pub use entity::EntityType;
@@ -17,8 +59,30 @@ pub use entity::EntityType;
 pub use registration_number::RegistrationNumber;
 pub use registration_number::RegistrationNumberError;
 
+pub use registry::CountryRegistry;
+pub use registry::RegistryChain;
+pub use registry::RegistryProvider;
+pub use registry::StaticRegistry;
+
 pub use address::Address;
 
+pub use bank_account::BankAccount;
+pub use bank_account::BankAccountError;
+pub use bank_account::CzBankAccount;
+
+pub use bank_code::Bank;
+pub use bank_code::BankRegistry;
+pub use bank_code::BankRegistryError;
+
+pub use country::CountryCode;
+
 pub use payment_method::PaymentMethod;
 
 pub use time::Time;
+
+pub use vat::PriceType;
+pub use vat::VatRate;
+pub use vat::VatSummary;
+
+pub use vat_number::VatNumber;
+pub use vat_number::VatNumberError;
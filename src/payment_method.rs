@@ -11,6 +11,17 @@ pub enum PaymentMethod {
     BankTransfer(String), // variable symbol
 }
 
+impl PaymentMethod {
+    /// Returns the name of the payment method as printed on the invoice.
+    pub fn name(&self) -> &'static str {
+        match self {
+            PaymentMethod::Cash => "Hotově",
+            PaymentMethod::Card(_) => "Platební kartou",
+            PaymentMethod::BankTransfer(_) => "Bankovním převodem",
+        }
+    }
+}
+
 impl Display for PaymentMethod {
     fn fmt(
         &self,
@@ -3,15 +3,27 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
+use serde::Serializer;
 
-/// Representation of czech company registration number(IČO).
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
-pub struct RegistrationNumber(String);
+use crate::country::CountryCode;
+
+/// Company registration number together with the country which issued it.
+///
+/// Czech IČO is written without the country, other numbers are prefixed
+/// with the country code, e.g. `SK:31333532` or `AT:FN 56247t`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RegistrationNumber {
+    country: CountryCode,
+    number: String,
+}
 
 #[derive(Debug)]
 pub enum RegistrationNumberError {
-    InvalidNumber,
+    /// The number doesn't pass the validation of the country
+    InvalidNumber(CountryCode),
+    UnknownCountry,
 }
 
 impl Display for RegistrationNumberError {
@@ -20,10 +32,17 @@ impl Display for RegistrationNumberError {
         f: &mut std::fmt::Formatter<'_>,
     ) -> std::fmt::Result {
         match self {
-            RegistrationNumberError::InvalidNumber => {
+            RegistrationNumberError::InvalidNumber(country) => {
                 write!(
                     f,
-                    "Neplatné IČO"
+                    "Neplatné {}",
+                    country_label(*country)
+                )
+            }
+            RegistrationNumberError::UnknownCountry => {
+                write!(
+                    f,
+                    "Neznámý kód státu"
                 )
             }
         }
@@ -32,8 +51,78 @@ impl Display for RegistrationNumberError {
 
 impl Error for RegistrationNumberError {}
 
+/// Name of the registration numbers issued in the country
+fn country_label(country: CountryCode) -> &'static str {
+    match country {
+        CountryCode::Cz | CountryCode::Sk => "IČO",
+        CountryCode::De => "Handelsregister",
+        CountryCode::Pl => "REGON/NIP",
+        CountryCode::At => "Firmenbuch",
+        _ => "Reg. č.",
+    }
+}
+
+/// Weighted sum of the digits, `None` if any character isn't a digit
+pub(crate) fn weighted_sum(
+    digits: &str,
+    weights: &[u32],
+) -> Option<u32> {
+    digits
+        .chars()
+        .zip(weights)
+        .map(|(ch, weight)| Some(ch.to_digit(10)? * weight))
+        .sum()
+}
+
+pub(crate) fn all_digits(number: &str) -> bool {
+    !number.is_empty()
+        && number
+            .chars()
+            .all(|x| x.is_ascii_digit())
+}
+
 impl RegistrationNumber {
+    /// Creates the registration number, validating it by the rules of the country.
+    pub fn new(
+        country: CountryCode,
+        number: &str,
+    ) -> Result<Self, RegistrationNumberError> {
+        let number = match country {
+            CountryCode::Cz | CountryCode::Sk => {
+                let number = number.trim();
+
+                if number.len() != 8 {
+                    return Err(RegistrationNumberError::InvalidNumber(country));
+                }
+
+                Self::valid(number).map(|_| number.to_string())
+            }
+            CountryCode::De => Self::valid_de(number),
+            CountryCode::Pl => Self::valid_pl(number),
+            CountryCode::At => Self::valid_at(number),
+            _ => {
+                let number = number.trim();
+
+                number
+                    .chars()
+                    .all(|x| x.is_ascii_alphanumeric() || x == ' ' || x == '-')
+                    .then(|| number.to_string())
+                    .filter(|x| !x.is_empty())
+            }
+        }
+        .ok_or(RegistrationNumberError::InvalidNumber(country))?;
+
+        Ok(
+            Self {
+                country,
+                number,
+            },
+        )
+    }
+
     /// Checks if the Czech registration number is valid.
+    ///
+    /// Slovak IČO uses the same checksum.
     fn valid(number: &str) -> Option<()> {
         let control_char = number
             .chars()
@@ -62,9 +151,176 @@ impl RegistrationNumber {
         Some(())
     }
 
+    /// Checks the German Handelsregister number, e.g. `HRB 12345 B`.
+    ///
+    /// The numbers have no checksum, only the register type and the format are checked.
+    fn valid_de(number: &str) -> Option<String> {
+        let number = number.trim();
+
+        let register = [
+            "HRA", "HRB", "GnR", "GsR", "PR", "VR",
+        ]
+        .into_iter()
+        .find(|x| number.starts_with(x))?;
+
+        let mut parts = number[register.len()..].split_whitespace();
+        let digits = parts.next()?;
+
+        if !all_digits(digits) || digits.len() > 6 {
+            return None;
+        }
+
+        let suffix = parts.next();
+
+        if parts
+            .next()
+            .is_some()
+            || suffix.is_some_and(
+                |x| {
+                    !x.chars()
+                        .all(|x| x.is_ascii_alphabetic())
+                },
+            )
+        {
+            return None;
+        }
+
+        Some(
+            match suffix {
+                Some(suffix) => {
+                    format!(
+                        "{} {} {}",
+                        register, digits, suffix
+                    )
+                }
+                None => {
+                    format!(
+                        "{} {}",
+                        register, digits
+                    )
+                }
+            },
+        )
+    }
+
+    /// Checks the Polish REGON (9 or 14 digits) or NIP (10 digits).
+    fn valid_pl(number: &str) -> Option<String> {
+        let number: String = number
+            .chars()
+            .filter(|x| *x != '-' && !x.is_whitespace())
+            .collect();
+
+        if !all_digits(&number) {
+            return None;
+        }
+
+        let control = number
+            .chars()
+            .last()?
+            .to_digit(10)?;
+
+        let valid = match number.len() {
+            9 => {
+                weighted_sum(
+                    &number,
+                    &[
+                        8, 9, 2, 3, 4, 5, 6, 7,
+                    ],
+                )? % 11
+                    % 10
+                    == control
+            }
+            14 => {
+                Self::valid_pl(&number[..9]).is_some()
+                    && weighted_sum(
+                        &number,
+                        &[
+                            2, 4, 8, 5, 0, 9, 7, 3, 6, 1, 2, 4, 8,
+                        ],
+                    )? % 11
+                        % 10
+                        == control
+            }
+            10 => {
+                weighted_sum(
+                    &number,
+                    &[
+                        6, 5, 7, 2, 3, 4, 5, 6, 7,
+                    ],
+                )? % 11
+                    == control
+            }
+            _ => false,
+        };
+
+        valid.then_some(number)
+    }
+
+    /// Checks the Austrian Firmenbuch number, e.g. `FN 56247t`.
+    fn valid_at(number: &str) -> Option<String> {
+        const CHECK_LETTERS: &[u8] = b"abdfghikmpstvwxyz";
+
+        let number = number
+            .trim()
+            .to_lowercase();
+        let number = number
+            .strip_prefix("fn")
+            .unwrap_or(&number)
+            .trim();
+
+        let check = number
+            .chars()
+            .last()?;
+        let digits = &number[..number.len() - check.len_utf8()];
+
+        if !all_digits(digits) || digits.len() > 6 {
+            return None;
+        }
+
+        let sum = weighted_sum(
+            &format!(
+                "{:0>6}",
+                digits
+            ),
+            &[
+                6, 4, 14, 15, 10, 1,
+            ],
+        )?;
+
+        (CHECK_LETTERS[(sum % 17) as usize] as char == check).then(
+            || {
+                format!(
+                    "FN {}{}",
+                    digits, check
+                )
+            },
+        )
+    }
+
     /// Returns the registration number as a string.
     pub fn get(&self) -> &str {
-        &self.0
+        &self.number
+    }
+
+    /// Returns the country which issued the number.
+    pub fn country(&self) -> CountryCode {
+        self.country
+    }
+
+    /// Returns the name of the number used on invoices.
+    pub fn label(&self) -> &'static str {
+        match self.country {
+            CountryCode::Pl
+                if self
+                    .number
+                    .len()
+                    == 10 =>
+            {
+                "NIP"
+            }
+            CountryCode::Pl => "REGON",
+            country => country_label(country),
+        }
     }
 }
 
@@ -73,7 +329,7 @@ impl Display for RegistrationNumber {
         &self,
         f: &mut std::fmt::Formatter<'_>,
     ) -> std::fmt::Result {
-        self.0
+        self.number
             .fmt(f)
     }
 }
@@ -81,14 +337,51 @@ impl Display for RegistrationNumber {
 impl FromStr for RegistrationNumber {
     type Err = RegistrationNumberError;
 
+    /// Parses `27082440` as Czech IČO, other countries are written as `SK:31333532`.
     fn from_str(number: &str) -> Result<Self, Self::Err> {
-        if number.len() != 8 {
-            return Err(Self::Err::InvalidNumber);
+        match number.split_once(':') {
+            Some((country, number)) => {
+                Self::new(
+                    country
+                        .parse()
+                        .or(Err(Self::Err::UnknownCountry))?,
+                    number,
+                )
+            }
+            None => {
+                Self::new(
+                    CountryCode::Cz,
+                    number,
+                )
+            }
+        }
+    }
+}
+
+impl Serialize for RegistrationNumber {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self.country {
+            CountryCode::Cz => serializer.serialize_str(&self.number),
+            country => {
+                serializer.serialize_str(
+                    &format!(
+                        "{}:{}",
+                        country, self.number
+                    ),
+                )
+            }
         }
+    }
+}
 
-        Self::valid(number)
-            .ok_or(Self::Err::InvalidNumber)
-            .map(|_| Self(number.to_string()))
+impl<'de> Deserialize<'de> for RegistrationNumber {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -96,6 +389,7 @@ impl FromStr for RegistrationNumber {
 mod tests {
     use super::RegistrationNumber;
     use super::RegistrationNumberError;
+    use crate::country::CountryCode;
 
     #[test]
     fn test_registration_number() {
@@ -115,4 +409,102 @@ mod tests {
             "27082440"
         );
     }
+
+    #[test]
+    fn test_foreign_registration_numbers() {
+        let valid = [
+            (
+                "SK:31333532",
+                CountryCode::Sk,
+                "31333532",
+            ),
+            (
+                "DE:HRB 86891 B",
+                CountryCode::De,
+                "HRB 86891 B",
+            ),
+            (
+                "PL:123456785",
+                CountryCode::Pl,
+                "123456785",
+            ),
+            (
+                "PL:12345678512347",
+                CountryCode::Pl,
+                "12345678512347",
+            ),
+            (
+                "PL:526-025-02-74",
+                CountryCode::Pl,
+                "5260250274",
+            ),
+            (
+                "AT:FN 56247t",
+                CountryCode::At,
+                "FN 56247t",
+            ),
+        ];
+
+        for (value, country, number) in valid {
+            let parsed: RegistrationNumber = value
+                .parse()
+                .unwrap();
+
+            assert_eq!(
+                parsed.country(),
+                country
+            );
+            assert_eq!(
+                parsed.get(),
+                number
+            );
+        }
+
+        for value in [
+            "SK:31333533",
+            "DE:XYZ 123",
+            "PL:123456784",
+            "PL:5260250275",
+            "AT:FN 56247a",
+            "XX:123",
+        ] {
+            assert!(
+                value
+                    .parse::<RegistrationNumber>()
+                    .is_err()
+            );
+        }
+
+        assert_eq!(
+            "DE:XYZ 123"
+                .parse::<RegistrationNumber>()
+                .unwrap_err()
+                .to_string(),
+            "Neplatné Handelsregister"
+        );
+        assert_eq!(
+            "SK:31333533"
+                .parse::<RegistrationNumber>()
+                .unwrap_err()
+                .to_string(),
+            "Neplatné IČO"
+        );
+    }
+
+    #[test]
+    fn test_serde() {
+        let czech: RegistrationNumber = serde_json::from_str("\"27082440\"").unwrap();
+        let slovak: RegistrationNumber = "SK:31333532"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&czech).unwrap(),
+            "\"27082440\""
+        );
+        assert_eq!(
+            serde_json::to_string(&slovak).unwrap(),
+            "\"SK:31333532\""
+        );
+    }
 }
@@ -0,0 +1,648 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+#[cfg(
+    any(
+        feature = "blocking",
+        feature = "async"
+    )
+)]
+use std::sync::Arc;
+#[cfg(
+    any(
+        feature = "blocking",
+        feature = "async"
+    )
+)]
+use std::time::Duration;
+
+#[cfg(
+    any(
+        feature = "blocking",
+        feature = "async"
+    )
+)]
+use crate::ares::AresCache;
+use crate::country::CountryCode;
+use crate::entity::eu::Entity;
+use crate::registration_number::RegistrationNumber;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The registry doesn't know the registration number
+    NotFound(RegistrationNumber),
+    /// The registry failed to look up the subject
+    ProviderError(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl Display for Error {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            Error::NotFound(number) => {
+                write!(
+                    f,
+                    "Subject {} was not found in the registry",
+                    number
+                )
+            }
+            Error::ProviderError(e) => {
+                write!(
+                    f,
+                    "Registry error: {}",
+                    e
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(
+    any(
+        feature = "blocking",
+        feature = "async"
+    )
+)]
+impl From<crate::http::Error> for Error {
+    fn from(error: crate::http::Error) -> Self {
+        match error {
+            crate::http::Error::NotFound(number) => Error::NotFound(number),
+            error => Error::ProviderError(Box::new(error)),
+        }
+    }
+}
+
+/// Source of entities, resolves a registration number into an [`Entity`]
+pub trait RegistryProvider {
+    fn resolve(
+        &self,
+        number: &RegistrationNumber,
+    ) -> Result<Entity, Error>;
+}
+
+/// Future returned by [`AsyncRegistryProvider::resolve`]
+#[cfg(feature = "async")]
+pub type ResolveFuture<'a> = Pin<Box<dyn Future<Output = Result<Entity, Error>> + Send + 'a>>;
+
+/// Asynchronous source of entities, the counterpart of [`RegistryProvider`]
+#[cfg(feature = "async")]
+pub trait AsyncRegistryProvider: Send + Sync {
+    fn resolve<'a>(
+        &'a self,
+        number: &'a RegistrationNumber,
+    ) -> ResolveFuture<'a>;
+}
+
+#[cfg(feature = "blocking")]
+impl RegistryProvider for crate::ares::AresClient {
+    fn resolve(
+        &self,
+        number: &RegistrationNumber,
+    ) -> Result<Entity, Error> {
+        Ok(self.fetch(number.clone())?)
+    }
+}
+
+#[cfg(
+    all(
+        feature = "blocking",
+        feature = "rpo"
+    )
+)]
+impl RegistryProvider for crate::rpo::RpoClient {
+    fn resolve(
+        &self,
+        number: &RegistrationNumber,
+    ) -> Result<Entity, Error> {
+        Ok(self.fetch(number.clone())?)
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncRegistryProvider for crate::ares::AsyncAresClient {
+    fn resolve<'a>(
+        &'a self,
+        number: &'a RegistrationNumber,
+    ) -> ResolveFuture<'a> {
+        Box::pin(
+            async move {
+                Ok(
+                    self.fetch(number.clone())
+                        .await?,
+                )
+            },
+        )
+    }
+}
+
+#[cfg(
+    all(
+        feature = "async",
+        feature = "rpo"
+    )
+)]
+impl AsyncRegistryProvider for crate::rpo::AsyncRpoClient {
+    fn resolve<'a>(
+        &'a self,
+        number: &'a RegistrationNumber,
+    ) -> ResolveFuture<'a> {
+        Box::pin(
+            async move {
+                Ok(
+                    self.fetch(number.clone())
+                        .await?,
+                )
+            },
+        )
+    }
+}
+
+/// Resolves the subjects stored in the ARES cache without asking ARES,
+/// put it in front of the clients to skip the requests for the subjects fetched before.
+///
+/// Missing and expired entries are reported as [`Error::NotFound`], so the chain goes on.
+#[cfg(
+    any(
+        feature = "blocking",
+        feature = "async"
+    )
+)]
+#[derive(Debug, Clone)]
+pub struct CacheRegistry {
+    cache: Arc<dyn AresCache>,
+    ttl: Duration,
+}
+
+#[cfg(
+    any(
+        feature = "blocking",
+        feature = "async"
+    )
+)]
+impl CacheRegistry {
+    /// Entries older than `ttl` are ignored
+    pub fn new(
+        cache: Arc<dyn AresCache>,
+        ttl: Duration,
+    ) -> Self {
+        Self {
+            cache,
+            ttl,
+        }
+    }
+}
+
+#[cfg(
+    any(
+        feature = "blocking",
+        feature = "async"
+    )
+)]
+impl RegistryProvider for CacheRegistry {
+    fn resolve(
+        &self,
+        number: &RegistrationNumber,
+    ) -> Result<Entity, Error> {
+        let entry = self
+            .cache
+            .get(number)
+            .filter(|entry| entry.is_fresh(self.ttl))
+            .ok_or_else(|| Error::NotFound(number.clone()))?;
+
+        Ok(
+            crate::ares::to_entity(
+                number.clone(),
+                entry.subject,
+            )?,
+        )
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncRegistryProvider for CacheRegistry {
+    fn resolve<'a>(
+        &'a self,
+        number: &'a RegistrationNumber,
+    ) -> ResolveFuture<'a> {
+        Box::pin(
+            std::future::ready(
+                RegistryProvider::resolve(
+                    self, number,
+                ),
+            ),
+        )
+    }
+}
+
+/// Registry of entities entered by hand, useful as the last fallback or in tests
+#[derive(Debug, Clone, Default)]
+pub struct StaticRegistry {
+    entities: HashMap<RegistrationNumber, Entity>,
+}
+
+impl StaticRegistry {
+    pub fn new(entities: impl IntoIterator<Item = Entity>) -> Self {
+        Self {
+            entities: entities
+                .into_iter()
+                .map(
+                    |entity| {
+                        (
+                            entity
+                                .identifier
+                                .clone(),
+                            entity,
+                        )
+                    },
+                )
+                .collect(),
+        }
+    }
+
+    pub fn insert(
+        &mut self,
+        entity: Entity,
+    ) {
+        self.entities
+            .insert(
+                entity
+                    .identifier
+                    .clone(),
+                entity,
+            );
+    }
+}
+
+impl RegistryProvider for StaticRegistry {
+    fn resolve(
+        &self,
+        number: &RegistrationNumber,
+    ) -> Result<Entity, Error> {
+        self.entities
+            .get(number)
+            .cloned()
+            .ok_or_else(|| Error::NotFound(number.clone()))
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncRegistryProvider for StaticRegistry {
+    fn resolve<'a>(
+        &'a self,
+        number: &'a RegistrationNumber,
+    ) -> ResolveFuture<'a> {
+        Box::pin(
+            std::future::ready(
+                RegistryProvider::resolve(
+                    self, number,
+                ),
+            ),
+        )
+    }
+}
+
+/// Asks the providers in order until one of them resolves the number.
+///
+/// When all of them fail, the first error other than [`Error::NotFound`] is returned,
+/// so an unavailable registry isn't reported as a missing subject.
+#[derive(Default)]
+pub struct RegistryChain {
+    providers: Vec<Box<dyn RegistryProvider>>,
+}
+
+impl RegistryChain {
+    pub fn new(providers: Vec<Box<dyn RegistryProvider>>) -> Self {
+        Self {
+            providers,
+        }
+    }
+
+    pub fn push(
+        &mut self,
+        provider: impl RegistryProvider + 'static,
+    ) {
+        self.providers
+            .push(Box::new(provider));
+    }
+}
+
+impl RegistryProvider for RegistryChain {
+    fn resolve(
+        &self,
+        number: &RegistrationNumber,
+    ) -> Result<Entity, Error> {
+        let mut failure = None;
+
+        for provider in &self.providers {
+            match provider.resolve(number) {
+                Ok(entity) => return Ok(entity),
+                Err(Error::NotFound(_)) => {}
+                Err(error) => {
+                    failure.get_or_insert(error);
+                }
+            }
+        }
+
+        Err(failure.unwrap_or_else(|| Error::NotFound(number.clone())))
+    }
+}
+
+/// Asynchronous counterpart of [`RegistryChain`], the providers are asked one after another
+#[cfg(feature = "async")]
+#[derive(Default)]
+pub struct AsyncRegistryChain {
+    providers: Vec<Box<dyn AsyncRegistryProvider>>,
+}
+
+#[cfg(feature = "async")]
+impl AsyncRegistryChain {
+    pub fn new(providers: Vec<Box<dyn AsyncRegistryProvider>>) -> Self {
+        Self {
+            providers,
+        }
+    }
+
+    pub fn push(
+        &mut self,
+        provider: impl AsyncRegistryProvider + 'static,
+    ) {
+        self.providers
+            .push(Box::new(provider));
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncRegistryProvider for AsyncRegistryChain {
+    fn resolve<'a>(
+        &'a self,
+        number: &'a RegistrationNumber,
+    ) -> ResolveFuture<'a> {
+        Box::pin(
+            async move {
+                let mut failure = None;
+
+                for provider in &self.providers {
+                    match provider
+                        .resolve(number)
+                        .await
+                    {
+                        Ok(entity) => return Ok(entity),
+                        Err(Error::NotFound(_)) => {}
+                        Err(error) => {
+                            failure.get_or_insert(error);
+                        }
+                    }
+                }
+
+                Err(failure.unwrap_or_else(|| Error::NotFound(number.clone())))
+            },
+        )
+    }
+}
+
+/// Dispatches the number to the provider registered for the country which issued it,
+/// e.g. ARES for Czech and RPO for Slovak numbers.
+#[derive(Default)]
+pub struct CountryRegistry {
+    providers: HashMap<CountryCode, Box<dyn RegistryProvider>>,
+}
+
+impl CountryRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the provider for the country, replacing the previous one.
+    pub fn insert(
+        &mut self,
+        country: CountryCode,
+        provider: impl RegistryProvider + 'static,
+    ) {
+        self.providers
+            .insert(
+                country,
+                Box::new(provider),
+            );
+    }
+}
+
+impl RegistryProvider for CountryRegistry {
+    fn resolve(
+        &self,
+        number: &RegistrationNumber,
+    ) -> Result<Entity, Error> {
+        self.providers
+            .get(&number.country())
+            .ok_or_else(|| Error::NotFound(number.clone()))?
+            .resolve(number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::entity;
+
+    struct Unavailable;
+
+    impl RegistryProvider for Unavailable {
+        fn resolve(
+            &self,
+            _: &RegistrationNumber,
+        ) -> Result<Entity, Error> {
+            Err(Error::ProviderError("Registry is down".into()))
+        }
+    }
+
+    #[test]
+    fn test_chain() {
+        let mut chain = RegistryChain::default();
+        chain.push(Unavailable);
+        chain.push(StaticRegistry::new([entity("27082440")]));
+
+        assert_eq!(
+            chain
+                .resolve(
+                    &"27082440"
+                        .parse()
+                        .unwrap()
+                )
+                .unwrap(),
+            entity("27082440")
+        );
+        assert!(
+            matches!(
+                chain.resolve(
+                    &"25596641"
+                        .parse()
+                        .unwrap()
+                ),
+                Err(Error::ProviderError(_))
+            )
+        );
+
+        let chain = RegistryChain::new(vec![Box::new(StaticRegistry::default())]);
+
+        assert!(
+            matches!(
+                chain.resolve(
+                    &"25596641"
+                        .parse()
+                        .unwrap()
+                ),
+                Err(Error::NotFound(_))
+            )
+        );
+    }
+
+    #[cfg(
+        any(
+            feature = "blocking",
+            feature = "async"
+        )
+    )]
+    #[test]
+    fn test_cache_registry() {
+        use crate::ares::tests::SUBJECT;
+        use crate::ares::CacheEntry;
+        use crate::ares::MemoryCache;
+
+        let cache = Arc::new(MemoryCache::new());
+        let number: RegistrationNumber = "27082440"
+            .parse()
+            .unwrap();
+        cache.set(
+            &number,
+            CacheEntry::new(serde_json::from_str(SUBJECT).unwrap()),
+        );
+
+        let registry = CacheRegistry::new(
+            cache.clone(),
+            Duration::from_secs(60),
+        );
+
+        assert_eq!(
+            RegistryProvider::resolve(&registry, &number)
+                .unwrap()
+                .name,
+            "Alza.cz a.s."
+        );
+        assert!(
+            matches!(
+                RegistryProvider::resolve(
+                    &registry,
+                    &"25596641"
+                        .parse()
+                        .unwrap()
+                ),
+                Err(Error::NotFound(_))
+            )
+        );
+
+        let registry = CacheRegistry::new(
+            cache,
+            Duration::ZERO,
+        );
+
+        assert!(
+            matches!(
+                RegistryProvider::resolve(&registry, &number),
+                Err(Error::NotFound(_))
+            )
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_chain() {
+        struct AsyncUnavailable;
+
+        impl AsyncRegistryProvider for AsyncUnavailable {
+            fn resolve<'a>(
+                &'a self,
+                _: &'a RegistrationNumber,
+            ) -> ResolveFuture<'a> {
+                Box::pin(async { Err(Error::ProviderError("Registry is down".into())) })
+            }
+        }
+
+        let mut chain = AsyncRegistryChain::default();
+        chain.push(AsyncUnavailable);
+        chain.push(StaticRegistry::new([entity("27082440")]));
+
+        assert_eq!(
+            chain
+                .resolve(
+                    &"27082440"
+                        .parse()
+                        .unwrap()
+                )
+                .await
+                .unwrap(),
+            entity("27082440")
+        );
+        assert!(
+            matches!(
+                chain
+                    .resolve(
+                        &"25596641"
+                            .parse()
+                            .unwrap()
+                    )
+                    .await,
+                Err(Error::ProviderError(_))
+            )
+        );
+    }
+
+    #[test]
+    fn test_country_registry() {
+        let mut registry = CountryRegistry::new();
+        registry.insert(
+            CountryCode::Cz,
+            StaticRegistry::new([entity("27082440")]),
+        );
+        registry.insert(
+            CountryCode::Sk,
+            Unavailable,
+        );
+
+        assert!(
+            registry
+                .resolve(
+                    &"27082440"
+                        .parse()
+                        .unwrap()
+                )
+                .is_ok()
+        );
+        assert!(
+            matches!(
+                registry.resolve(
+                    &"SK:31333532"
+                        .parse()
+                        .unwrap()
+                ),
+                Err(Error::ProviderError(_))
+            )
+        );
+        assert!(
+            matches!(
+                registry.resolve(
+                    &"PL:123456785"
+                        .parse()
+                        .unwrap()
+                ),
+                Err(Error::NotFound(_))
+            )
+        );
+    }
+}
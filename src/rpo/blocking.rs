@@ -0,0 +1,264 @@
+use super::client::parse_subject;
+use super::client::parse_vat_number;
+use super::client::to_entity;
+use super::client::validate;
+use super::Error;
+use super::RpoConfig;
+use super::RpoSubject;
+use crate::entity::eu::Entity;
+use crate::registration_number::RegistrationNumber;
+use crate::vat_number::VatNumber;
+
+/// Blocking client of the Slovak register of legal entities
+#[derive(Debug, Clone)]
+pub struct RpoClient {
+    config: RpoConfig,
+    client: reqwest::blocking::Client,
+}
+
+impl RpoClient {
+    pub fn new(config: RpoConfig) -> Result<Self, Error> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout)
+            .build()
+            .map_err(Error::TransportError)?;
+
+        Ok(
+            Self {
+                config,
+                client,
+            },
+        )
+    }
+
+    /// Fetches the full record of the subject from RPO.
+    ///
+    /// Non-Slovak numbers are refused with `Error::UnsupportedCountry` before anything is sent.
+    pub fn fetch_subject(
+        &self,
+        number: &RegistrationNumber,
+    ) -> Result<RpoSubject, Error> {
+        validate(number)?;
+
+        let url = self
+            .config
+            .subject_url(number);
+
+        let body = self
+            .config
+            .retry()
+            .send_blocking(
+                || {
+                    self.client
+                        .get(&url)
+                },
+                Some(number),
+                None,
+            )?;
+
+        parse_subject(
+            number, &body,
+        )
+    }
+
+    /// Fetches IČ DPH of the subject, `None` if it isn't a VAT payer.
+    ///
+    /// RPO doesn't know whether the subject is a VAT payer, without the API key it fails with `Error::MissingApiKey`.
+    pub fn fetch_vat_number(
+        &self,
+        number: &RegistrationNumber,
+    ) -> Result<Option<VatNumber>, Error> {
+        validate(number)?;
+
+        let Some(key) = &self
+            .config
+            .vat_api_key
+        else {
+            return Err(Error::MissingApiKey);
+        };
+
+        let url = self
+            .config
+            .vat_url(number);
+
+        let body = self
+            .config
+            .retry()
+            .send_blocking(
+                || {
+                    self.client
+                        .get(&url)
+                        .header(
+                            "key", key,
+                        )
+                },
+                None,
+                None,
+            )?;
+
+        parse_vat_number(&body)
+    }
+
+    /// Fetches the subject from RPO and converts it into an entity
+    pub fn fetch(
+        &self,
+        number: RegistrationNumber,
+    ) -> Result<Entity, Error> {
+        let subject = self.fetch_subject(&number)?;
+        let vat_number = self.fetch_vat_number(&number)?;
+
+        Ok(
+            to_entity(
+                number, subject, vat_number,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::tests::serve;
+    use crate::rpo::tests::SUBJECT;
+    use crate::rpo::tests::VAT_PAYER;
+
+    #[test]
+    fn test_fetch() {
+        let base_url = serve(
+            vec![
+                (
+                    200, SUBJECT,
+                ),
+                (
+                    200, VAT_PAYER,
+                ),
+            ],
+        );
+
+        let client = RpoClient::new(
+            RpoConfig {
+                base_url: base_url.clone(),
+                vat_base_url: base_url,
+                vat_api_key: Some("key".to_string()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let entity = client
+            .fetch(
+                "SK:31333532"
+                    .parse()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            entity.name,
+            "ESET, spol. s r.o."
+        );
+        assert_eq!(
+            entity
+                .vat_number
+                .map(|x| x.to_string())
+                .as_deref(),
+            Some("SK2020317068")
+        );
+        assert_eq!(
+            entity
+                .address
+                .street,
+            "Einsteinova"
+        );
+        assert_eq!(
+            entity
+                .address
+                .house_number,
+            "24"
+        );
+    }
+
+    #[test]
+    fn test_not_found() {
+        let client = RpoClient::new(
+            RpoConfig {
+                base_url: serve(
+                    vec![
+                        (
+                            200,
+                            r#"{"results": []}"#,
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert!(
+            matches!(
+                client.fetch(
+                    "SK:31333532"
+                        .parse()
+                        .unwrap()
+                ),
+                Err(Error::NotFound(_))
+            )
+        );
+    }
+
+    #[test]
+    fn test_missing_api_key() {
+        let base_url = serve(
+            vec![
+                (
+                    200, SUBJECT,
+                ),
+            ],
+        );
+
+        let client = RpoClient::new(
+            RpoConfig {
+                base_url: base_url.clone(),
+                vat_base_url: base_url,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        // The subject is known, but not whether it's a VAT payer
+        assert!(
+            matches!(
+                client.fetch(
+                    "SK:31333532"
+                        .parse()
+                        .unwrap()
+                ),
+                Err(Error::MissingApiKey)
+            )
+        );
+    }
+
+    #[test]
+    fn test_unsupported_country() {
+        let client = RpoClient::new(
+            RpoConfig {
+                base_url: serve(vec![]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert!(
+            matches!(
+                client.fetch_subject(
+                    &"27082440"
+                        .parse()
+                        .unwrap()
+                ),
+                Err(Error::UnsupportedCountry(_))
+            )
+        );
+    }
+}
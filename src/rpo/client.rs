@@ -0,0 +1,202 @@
+use serde::Deserialize;
+
+use super::Error;
+use super::RpoConfig;
+use super::RpoSubject;
+use crate::address::Address;
+use crate::country::CountryCode;
+use crate::entity::eu::Entity;
+use crate::http;
+use crate::http::Retry;
+use crate::registration_number::RegistrationNumber;
+use crate::vat_number::VatNumber;
+
+/// Response of the `search` endpoint
+#[derive(Debug, Deserialize)]
+struct RpoSearchResponse {
+    #[serde(default)]
+    results: Vec<RpoSubject>,
+}
+
+/// Response of the VAT payers list
+#[derive(Debug, Deserialize)]
+struct VatPayersResponse {
+    #[serde(default)]
+    data: Vec<VatPayer>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VatPayer {
+    ic_dph: Option<String>,
+}
+
+/// RPO only knows Slovak subjects, other numbers are refused before anything is sent
+pub(super) fn validate(number: &RegistrationNumber) -> Result<(), Error> {
+    match number.country() {
+        CountryCode::Sk => Ok(()),
+        country => {
+            Err(
+                Error::UnsupportedCountry(
+                    format!(
+                        "{}:{}",
+                        country, number
+                    ),
+                ),
+            )
+        }
+    }
+}
+
+impl RpoConfig {
+    pub(super) fn subject_url(
+        &self,
+        number: &RegistrationNumber,
+    ) -> String {
+        http::url(
+            &self.base_url,
+            &format!(
+                "search?identifier={}",
+                number.get()
+            ),
+        )
+    }
+
+    pub(super) fn vat_url(
+        &self,
+        number: &RegistrationNumber,
+    ) -> String {
+        http::url(
+            &self.vat_base_url,
+            &format!(
+                "search?column=ico&search={}",
+                number.get()
+            ),
+        )
+    }
+
+    pub(super) fn retry(&self) -> Retry {
+        Retry {
+            max_retries: self.max_retries,
+            backoff: self.backoff,
+            max_retry_after: self.max_retry_after,
+        }
+    }
+}
+
+/// Parses the search response, an empty result means the subject doesn't exist
+pub(super) fn parse_subject(
+    number: &RegistrationNumber,
+    body: &str,
+) -> Result<RpoSubject, Error> {
+    let response: RpoSearchResponse = serde_json::from_str(body).map_err(Error::JsonError)?;
+
+    response
+        .results
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::NotFound(number.clone()))
+}
+
+/// IČ DPH which doesn't pass the validation is left out
+pub(super) fn parse_vat_number(body: &str) -> Result<Option<VatNumber>, Error> {
+    let response: VatPayersResponse = serde_json::from_str(body).map_err(Error::JsonError)?;
+
+    Ok(
+        response
+            .data
+            .into_iter()
+            .find_map(
+                |x| {
+                    x.ic_dph?
+                        .parse()
+                        .ok()
+                },
+            ),
+    )
+}
+
+pub(super) fn to_entity(
+    number: RegistrationNumber,
+    subject: RpoSubject,
+    vat_number: Option<VatNumber>,
+) -> Entity {
+    let address = subject.address();
+
+    let municipality = address
+        .and_then(
+            |x| {
+                x.municipality
+                    .as_ref()
+            },
+        )
+        .map(
+            |x| {
+                x.value
+                    .clone()
+            },
+        )
+        .unwrap_or_default();
+
+    // Small villages don't have streets, the municipality is used instead
+    let street = address
+        .and_then(
+            |x| {
+                x.street
+                    .clone()
+            },
+        )
+        .unwrap_or(municipality.clone());
+
+    let building_number = address
+        .and_then(
+            |x| {
+                x.building_number
+                    .as_deref()
+            },
+        )
+        .map(str::trim)
+        .filter(|x| !x.is_empty());
+
+    let house_number = match (
+        address.and_then(|x| x.reg_number),
+        building_number,
+    ) {
+        (Some(reg_number), Some(building_number)) if reg_number > 0 => {
+            format!(
+                "{}/{}",
+                reg_number, building_number
+            )
+        }
+        (Some(reg_number), None) if reg_number > 0 => reg_number.to_string(),
+        (_, building_number) => {
+            building_number
+                .unwrap_or_default()
+                .to_string()
+        }
+    };
+
+    let country = number.country();
+
+    Entity::new(
+        number,
+        subject
+            .name()
+            .unwrap_or_default(),
+        Address::new(
+            country,
+            municipality,
+            street,
+            address
+                .and_then(
+                    |x| {
+                        x.postal_codes
+                            .first()
+                            .cloned()
+                    },
+                )
+                .unwrap_or_default(),
+            house_number,
+        ),
+        vat_number,
+    )
+}
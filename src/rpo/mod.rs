@@ -0,0 +1,213 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde::Serialize;
+use std::time::Duration;
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(
+    any(
+        feature = "blocking",
+        feature = "async"
+    )
+)]
+mod client;
+#[cfg(feature = "async")]
+pub mod nonblocking;
+
+#[cfg(feature = "blocking")]
+pub use blocking::RpoClient;
+#[cfg(feature = "async")]
+pub use nonblocking::AsyncRpoClient;
+
+pub use crate::http::Error;
+
+/// Base URL of the public API of the Slovak register of legal entities (RPO)
+pub const DEFAULT_BASE_URL: &str = "https://api.statistics.sk/rpo/v1";
+
+/// Base URL of the list of VAT payers published by the Slovak financial administration
+pub const DEFAULT_VAT_BASE_URL: &str = "https://iz.opendata.financnasprava.sk/api/data/ds_dphs";
+
+/// Value of a historized attribute, valid in the given period
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RpoValue {
+    pub value: String,
+    pub valid_from: Option<NaiveDate>,
+    pub valid_to: Option<NaiveDate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RpoCodeValue {
+    pub value: String,
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RpoAddress {
+    pub street: Option<String>,
+    /// Súpisné číslo
+    pub reg_number: Option<u32>,
+    /// Orientačné číslo
+    pub building_number: Option<String>,
+    #[serde(default)]
+    pub postal_codes: Vec<String>,
+    pub municipality: Option<RpoCodeValue>,
+    pub country: Option<RpoCodeValue>,
+    pub valid_from: Option<NaiveDate>,
+    pub valid_to: Option<NaiveDate>,
+}
+
+/// Subject as returned by RPO
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RpoSubject {
+    #[serde(default)]
+    pub identifiers: Vec<RpoValue>,
+    #[serde(default)]
+    pub full_names: Vec<RpoValue>,
+    #[serde(default)]
+    pub addresses: Vec<RpoAddress>,
+    #[serde(rename = "establishment")]
+    pub established_on: Option<NaiveDate>,
+    #[serde(rename = "termination")]
+    pub terminated_on: Option<NaiveDate>,
+}
+
+impl RpoSubject {
+    /// Returns the current business name.
+    pub fn name(&self) -> Option<&str> {
+        self.full_names
+            .iter()
+            .rev()
+            .find(
+                |x| {
+                    x.valid_to
+                        .is_none()
+                },
+            )
+            .or(
+                self.full_names
+                    .last(),
+            )
+            .map(
+                |x| {
+                    x.value
+                        .as_str()
+                },
+            )
+    }
+
+    /// Returns the current registered office.
+    pub fn address(&self) -> Option<&RpoAddress> {
+        self.addresses
+            .iter()
+            .rev()
+            .find(
+                |x| {
+                    x.valid_to
+                        .is_none()
+                },
+            )
+            .or(
+                self.addresses
+                    .last(),
+            )
+    }
+}
+
+/// Configuration of the RPO client
+#[derive(Debug, Clone)]
+pub struct RpoConfig {
+    pub base_url: String,
+    pub vat_base_url: String,
+    /// Key of the open data API of the financial administration.
+    ///
+    /// RPO doesn't contain IČ DPH, without the key the VAT status is unknown and fetching an entity fails.
+    pub vat_api_key: Option<String>,
+    /// Timeout of the whole request
+    pub timeout: Duration,
+    pub connect_timeout: Duration,
+    /// How many times a request is retried after a 429 or 5xx response
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled with every next one
+    pub backoff: Duration,
+    /// Longest `Retry-After` the client waits for, a longer one fails with `Error::RateLimited`
+    pub max_retry_after: Duration,
+}
+
+impl Default for RpoConfig {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            vat_base_url: DEFAULT_VAT_BASE_URL.to_string(),
+            vat_api_key: None,
+            timeout: Duration::from_secs(10),
+            connect_timeout: Duration::from_secs(5),
+            max_retries: 3,
+            backoff: Duration::from_millis(500),
+            max_retry_after: Duration::from_secs(60),
+        }
+    }
+}
+
+#[cfg(
+    all(
+        test,
+        feature = "blocking"
+    )
+)]
+mod tests {
+    pub(crate) const SUBJECT: &str = r#"{
+        "results": [
+            {
+                "id": 1138943,
+                "identifiers": [
+                    {
+                        "value": "31333532",
+                        "validFrom": "1999-01-13"
+                    }
+                ],
+                "fullNames": [
+                    {
+                        "value": "ESET spol. s r.o.",
+                        "validFrom": "1999-01-13",
+                        "validTo": "2009-03-31"
+                    },
+                    {
+                        "value": "ESET, spol. s r.o.",
+                        "validFrom": "2009-04-01"
+                    }
+                ],
+                "addresses": [
+                    {
+                        "street": "Einsteinova",
+                        "regNumber": 0,
+                        "buildingNumber": "24",
+                        "postalCodes": ["85101"],
+                        "municipality": {
+                            "value": "Bratislava - mestská časť Petržalka",
+                            "code": "529460"
+                        },
+                        "country": {
+                            "value": "Slovenská republika",
+                            "code": "703"
+                        },
+                        "validFrom": "2012-01-01"
+                    }
+                ],
+                "establishment": "1992-03-20"
+            }
+        ]
+    }"#;
+
+    pub(crate) const VAT_PAYER: &str = r#"{
+        "data": [
+            {
+                "ico": "31333532",
+                "ic_dph": "SK2020317068"
+            }
+        ]
+    }"#;
+}
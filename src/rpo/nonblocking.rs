@@ -0,0 +1,123 @@
+use super::client::parse_subject;
+use super::client::parse_vat_number;
+use super::client::to_entity;
+use super::client::validate;
+use super::Error;
+use super::RpoConfig;
+use super::RpoSubject;
+use crate::entity::eu::Entity;
+use crate::registration_number::RegistrationNumber;
+use crate::vat_number::VatNumber;
+
+/// Asynchronous client of the Slovak register of legal entities, for use inside a tokio runtime
+#[derive(Debug, Clone)]
+pub struct AsyncRpoClient {
+    config: RpoConfig,
+    client: reqwest::Client,
+}
+
+impl AsyncRpoClient {
+    pub fn new(config: RpoConfig) -> Result<Self, Error> {
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout)
+            .build()
+            .map_err(Error::TransportError)?;
+
+        Ok(
+            Self {
+                config,
+                client,
+            },
+        )
+    }
+
+    /// Fetches the full record of the subject from RPO.
+    ///
+    /// Non-Slovak numbers are refused with `Error::UnsupportedCountry` before anything is sent.
+    pub async fn fetch_subject(
+        &self,
+        number: &RegistrationNumber,
+    ) -> Result<RpoSubject, Error> {
+        validate(number)?;
+
+        let url = self
+            .config
+            .subject_url(number);
+
+        let body = self
+            .config
+            .retry()
+            .send_async(
+                || {
+                    self.client
+                        .get(&url)
+                },
+                Some(number),
+                None,
+            )
+            .await?;
+
+        parse_subject(
+            number, &body,
+        )
+    }
+
+    /// Fetches IČ DPH of the subject, `None` if it isn't a VAT payer.
+    ///
+    /// RPO doesn't know whether the subject is a VAT payer, without the API key it fails with `Error::MissingApiKey`.
+    pub async fn fetch_vat_number(
+        &self,
+        number: &RegistrationNumber,
+    ) -> Result<Option<VatNumber>, Error> {
+        validate(number)?;
+
+        let Some(key) = &self
+            .config
+            .vat_api_key
+        else {
+            return Err(Error::MissingApiKey);
+        };
+
+        let url = self
+            .config
+            .vat_url(number);
+
+        let body = self
+            .config
+            .retry()
+            .send_async(
+                || {
+                    self.client
+                        .get(&url)
+                        .header(
+                            "key", key,
+                        )
+                },
+                None,
+                None,
+            )
+            .await?;
+
+        parse_vat_number(&body)
+    }
+
+    /// Fetches the subject from RPO and converts it into an entity
+    pub async fn fetch(
+        &self,
+        number: RegistrationNumber,
+    ) -> Result<Entity, Error> {
+        let subject = self
+            .fetch_subject(&number)
+            .await?;
+        let vat_number = self
+            .fetch_vat_number(&number)
+            .await?;
+
+        Ok(
+            to_entity(
+                number, subject, vat_number,
+            ),
+        )
+    }
+}
@@ -0,0 +1,201 @@
+use std::fmt::Display;
+
+use rust_decimal::Decimal;
+use rust_decimal::RoundingStrategy;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// VAT rate of an invoice item (sazba DPH).
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum VatRate {
+    /// Standard rate, 21 %
+    Standard,
+    /// Reduced rate, 12 %
+    Reduced,
+    /// Zero rate, 0 %
+    Zero,
+    /// Supply exempt from VAT (osvobozené plnění)
+    Exempt,
+}
+
+impl VatRate {
+    /// Returns the rate in percent.
+    pub fn percentage(&self) -> Decimal {
+        match self {
+            VatRate::Standard => {
+                Decimal::new(
+                    21, 0,
+                )
+            }
+            VatRate::Reduced => {
+                Decimal::new(
+                    12, 0,
+                )
+            }
+            VatRate::Zero | VatRate::Exempt => Decimal::ZERO,
+        }
+    }
+}
+
+impl Display for VatRate {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            VatRate::Exempt => {
+                write!(
+                    f,
+                    "Osvobozeno"
+                )
+            }
+            _ => {
+                write!(
+                    f,
+                    "{} %",
+                    self.percentage()
+                )
+            }
+        }
+    }
+}
+
+/// Whether the price of an item is stated with or without VAT.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PriceType {
+    #[default]
+    WithoutVat,
+    WithVat,
+}
+
+/// One row of the VAT recapitulation (rekapitulace DPH).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct VatSummary {
+    pub rate: VatRate,
+    pub base: Decimal,
+    pub vat: Decimal,
+    pub total: Decimal,
+}
+
+impl VatSummary {
+    /// Computes the summary of a single rate from the sum of prices without VAT
+    /// and the sum of prices with VAT, rounded to `decimals` places.
+    ///
+    /// VAT contained in prices with VAT is calculated as `total * rate / (100 + rate)`.
+    pub fn new(
+        rate: VatRate,
+        without_vat: Decimal,
+        with_vat: Decimal,
+        decimals: u32,
+    ) -> Self {
+        let round = |value: Decimal| {
+            value.round_dp_with_strategy(
+                decimals,
+                RoundingStrategy::MidpointAwayFromZero,
+            )
+        };
+
+        let without_vat = round(without_vat);
+        let with_vat = round(with_vat);
+        let percentage = rate.percentage();
+
+        let vat_from_base = round(without_vat * percentage / Decimal::ONE_HUNDRED);
+        let vat_from_total = round(with_vat * percentage / (Decimal::ONE_HUNDRED + percentage));
+
+        let base = without_vat + with_vat - vat_from_total;
+        let vat = vat_from_base + vat_from_total;
+
+        Self {
+            rate,
+            base,
+            vat,
+            total: base + vat,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_without_vat() {
+        let summary = VatSummary::new(
+            VatRate::Standard,
+            Decimal::new(
+                1000, 0,
+            ),
+            Decimal::ZERO,
+            2,
+        );
+
+        assert_eq!(
+            summary.base,
+            Decimal::new(1000, 0)
+        );
+        assert_eq!(
+            summary.vat,
+            Decimal::new(210, 0)
+        );
+        assert_eq!(
+            summary.total,
+            Decimal::new(1210, 0)
+        );
+    }
+
+    #[test]
+    fn test_summary_with_vat() {
+        let summary = VatSummary::new(
+            VatRate::Reduced,
+            Decimal::ZERO,
+            Decimal::new(
+                100, 0,
+            ),
+            2,
+        );
+
+        assert_eq!(
+            summary.vat,
+            Decimal::new(1071, 2)
+        );
+        assert_eq!(
+            summary.base,
+            Decimal::new(8929, 2)
+        );
+        assert_eq!(
+            summary.total,
+            Decimal::new(100, 0)
+        );
+    }
+
+    #[test]
+    fn test_exempt() {
+        let summary = VatSummary::new(
+            VatRate::Exempt,
+            Decimal::new(
+                500, 0,
+            ),
+            Decimal::new(
+                500, 0,
+            ),
+            2,
+        );
+
+        assert_eq!(
+            summary.vat,
+            Decimal::ZERO
+        );
+        assert_eq!(
+            summary.total,
+            Decimal::new(1000, 0)
+        );
+        assert_eq!(
+            VatRate::Exempt.to_string(),
+            "Osvobozeno"
+        );
+        assert_eq!(
+            VatRate::Standard.to_string(),
+            "21 %"
+        );
+    }
+}
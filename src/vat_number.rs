@@ -0,0 +1,976 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::country::CountryCode;
+use crate::registration_number::all_digits;
+use crate::registration_number::weighted_sum;
+
+/// VAT identification number (DIČ) of a subject registered in an EU member state.
+///
+/// The number is written with the country prefix, e.g. `CZ27082440`. Greece uses `EL`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VatNumber {
+    country: CountryCode,
+    number: String,
+}
+
+#[derive(Debug)]
+pub enum VatNumberError {
+    /// The prefix isn't a code of an EU member state
+    UnknownCountry(String),
+    /// The number doesn't have the length or characters used in the country
+    InvalidFormat(CountryCode),
+    /// The check digits don't match
+    InvalidChecksum(CountryCode),
+}
+
+impl Display for VatNumberError {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            VatNumberError::UnknownCountry(prefix) => {
+                write!(
+                    f,
+                    "Neznámý kód státu v DIČ: {}",
+                    prefix
+                )
+            }
+            VatNumberError::InvalidFormat(country) => {
+                write!(
+                    f,
+                    "DIČ neodpovídá formátu státu {}",
+                    country
+                )
+            }
+            VatNumberError::InvalidChecksum(country) => {
+                write!(
+                    f,
+                    "DIČ nemá platnou kontrolní číslici podle pravidel státu {}",
+                    country
+                )
+            }
+        }
+    }
+}
+
+impl Error for VatNumberError {}
+
+fn digits(
+    number: &str,
+    lengths: &[usize],
+) -> bool {
+    all_digits(number) && lengths.contains(&number.len())
+}
+
+fn digit(
+    number: &str,
+    index: usize,
+) -> u32 {
+    (number.as_bytes()[index] - b'0') as u32
+}
+
+fn value(number: &str) -> u64 {
+    number
+        .parse()
+        .unwrap_or_default()
+}
+
+fn luhn(number: &str) -> bool {
+    let sum: u32 = number
+        .chars()
+        .rev()
+        .enumerate()
+        .map(
+            |(i, ch)| {
+                let digit = ch
+                    .to_digit(10)
+                    .unwrap_or_default();
+
+                match i % 2 {
+                    0 => digit,
+                    _ => digit * 2 / 10 + digit * 2 % 10,
+                }
+            },
+        )
+        .sum();
+
+    sum.is_multiple_of(10)
+}
+
+/// ISO 7064 MOD 11,10, used in Germany and Croatia
+fn mod_11_10(number: &str) -> bool {
+    let product = number[..number.len() - 1]
+        .chars()
+        .fold(
+            10,
+            |product, ch| {
+                let sum = (ch
+                    .to_digit(10)
+                    .unwrap_or_default()
+                    + product)
+                    % 10;
+
+                let sum = match sum {
+                    0 => 10,
+                    sum => sum,
+                };
+
+                sum * 2 % 11
+            },
+        );
+
+    (11 - product) % 10
+        == digit(
+            number,
+            number.len() - 1,
+        )
+}
+
+/// ISO 7064 MOD 97-10 of the whole number including the prefix, letters count as 10 to 35
+fn mod_97(number: &str) -> bool {
+    number
+        .chars()
+        .filter_map(|x| x.to_digit(36))
+        .fold(
+            0,
+            |rest, value| {
+                match value {
+                    0..=9 => (rest * 10 + value) % 97,
+                    _ => (rest * 100 + value) % 97,
+                }
+            },
+        )
+        == 1
+}
+
+/// Czech birth number (rodné číslo), 9 digits before 1954 and 10 digits with a checksum since.
+fn birth_number(number: &str) -> bool {
+    let year = value(&number[..2]) as i32;
+    let month = value(&number[2..4]) as u32;
+    let day = value(&number[4..6]) as u32;
+
+    let month = match month {
+        51..=62 => month - 50,
+        71..=82 => month - 70,
+        21..=32 => month - 20,
+        month => month,
+    };
+
+    let year = match number.len() {
+        9 if year >= 54 => return false,
+        9 => 1900 + year,
+        _ if year < 54 => 2000 + year,
+        _ => 1900 + year,
+    };
+
+    if NaiveDate::from_ymd_opt(
+        year, month, day,
+    )
+    .is_none()
+    {
+        return false;
+    }
+
+    number.len() == 9
+        || value(number).is_multiple_of(11)
+        || value(&number[..9]) % 11 % 10
+            == digit(
+                number, 9,
+            ) as u64
+}
+
+impl VatNumber {
+    /// Creates the VAT number, the number is given without the country prefix.
+    ///
+    /// Spaces, dots and dashes are removed. The format is checked for every member state,
+    /// the check digits only where the algorithm is public (all states except Spain,
+    /// Ireland, Lithuania and Latvia).
+    pub fn new(
+        country: CountryCode,
+        number: &str,
+    ) -> Result<Self, VatNumberError> {
+        let number = number
+            .chars()
+            .filter(|x| !x.is_whitespace() && *x != '.' && *x != '-')
+            .collect::<String>()
+            .to_uppercase();
+
+        let format = |valid: bool| {
+            match valid {
+                true => Ok(()),
+                false => Err(VatNumberError::InvalidFormat(country)),
+            }
+        };
+
+        format(number.is_ascii() && !number.is_empty())?;
+
+        let n = number.as_str();
+
+        let checksum = match country {
+            CountryCode::At => {
+                format(n.len() == 9 && n.starts_with('U') && all_digits(&n[1..]))?;
+
+                let sum: u32 = n[1..8]
+                    .chars()
+                    .enumerate()
+                    .map(
+                        |(i, ch)| {
+                            let digit = ch
+                                .to_digit(10)
+                                .unwrap_or_default();
+
+                            match i % 2 {
+                                0 => digit,
+                                _ => digit * 2 / 10 + digit * 2 % 10,
+                            }
+                        },
+                    )
+                    .sum();
+
+                (10 - (sum + 4) % 10) % 10
+                    == digit(
+                        n, 8,
+                    )
+            }
+            CountryCode::Be => {
+                format(
+                    digits(
+                        n,
+                        &[10],
+                    ) && (n.starts_with('0') || n.starts_with('1')),
+                )?;
+
+                97 - value(&n[..8]) % 97 == value(&n[8..])
+            }
+            CountryCode::Bg => {
+                format(
+                    digits(
+                        n,
+                        &[
+                            9, 10,
+                        ],
+                    ),
+                )?;
+
+                match n.len() {
+                    9 => {
+                        let check = match weighted_sum(
+                            n,
+                            &[
+                                1, 2, 3, 4, 5, 6, 7, 8,
+                            ],
+                        )
+                        .unwrap_or_default()
+                            % 11
+                        {
+                            10 => {
+                                weighted_sum(
+                                    n,
+                                    &[
+                                        3, 4, 5, 6, 7, 8, 9, 10,
+                                    ],
+                                )
+                                .unwrap_or_default()
+                                    % 11
+                                    % 10
+                            }
+                            check => check,
+                        };
+
+                        check
+                            == digit(
+                                n, 8,
+                            )
+                    }
+                    _ => true,
+                }
+            }
+            CountryCode::Cy => {
+                format(
+                    n.len() == 9
+                        && all_digits(&n[..8])
+                        && n.ends_with(|x: char| x.is_ascii_uppercase()),
+                )?;
+
+                const ODD: [u32; 10] = [
+                    1, 0, 5, 7, 9, 13, 15, 17, 19, 21,
+                ];
+
+                let sum: u32 = (0..8)
+                    .map(
+                        |i| {
+                            match i % 2 {
+                                0 => {
+                                    ODD[digit(
+                                        n, i,
+                                    ) as usize]
+                                }
+                                _ => {
+                                    digit(
+                                        n, i,
+                                    )
+                                }
+                            }
+                        },
+                    )
+                    .sum();
+
+                (b'A' + (sum % 26) as u8) == n.as_bytes()[8]
+            }
+            CountryCode::Cz => {
+                format(
+                    digits(
+                        n,
+                        &[
+                            8, 9, 10,
+                        ],
+                    ) && !(n.len() == 8 && n.starts_with('9')),
+                )?;
+
+                match n.len() {
+                    // legal entities, DIČ is the IČO
+                    8 => {
+                        (11 - weighted_sum(
+                            n,
+                            &[
+                                8, 7, 6, 5, 4, 3, 2,
+                            ],
+                        )
+                        .unwrap_or_default()
+                            % 11)
+                            % 10
+                            == digit(
+                                n, 7,
+                            )
+                    }
+                    // VAT groups, the number is assigned without a check digit
+                    9 if n.starts_with("699") => true,
+                    // individuals without a birth number
+                    9 if n.starts_with('6') => {
+                        let sum = weighted_sum(
+                            &n[1..],
+                            &[
+                                8, 7, 6, 5, 4, 3, 2,
+                            ],
+                        )
+                        .unwrap_or_default()
+                            % 11;
+
+                        (8 - (10 - sum as i32) % 11).rem_euclid(10) as u32
+                            == digit(
+                                n, 8,
+                            )
+                    }
+                    _ => birth_number(n),
+                }
+            }
+            CountryCode::De => {
+                format(
+                    digits(
+                        n,
+                        &[9],
+                    ) && !n.starts_with('0'),
+                )?;
+
+                mod_11_10(n)
+            }
+            CountryCode::Dk => {
+                format(
+                    digits(
+                        n,
+                        &[8],
+                    ) && !n.starts_with('0'),
+                )?;
+
+                weighted_sum(
+                    n,
+                    &[
+                        2, 7, 6, 5, 4, 3, 2, 1,
+                    ],
+                )
+                .unwrap_or_default()
+                .is_multiple_of(11)
+            }
+            CountryCode::Ee => {
+                format(
+                    digits(
+                        n,
+                        &[9],
+                    ) && n.starts_with("10"),
+                )?;
+
+                weighted_sum(
+                    n,
+                    &[
+                        3, 7, 1, 3, 7, 1, 3, 7, 1,
+                    ],
+                )
+                .unwrap_or_default()
+                .is_multiple_of(10)
+            }
+            CountryCode::Es => {
+                format(
+                    n.len() == 9
+                        && all_digits(&n[1..8])
+                        && n.chars()
+                            .all(|x| x.is_ascii_alphanumeric()),
+                )?;
+
+                true
+            }
+            CountryCode::Fi => {
+                format(
+                    digits(
+                        n,
+                        &[8],
+                    ),
+                )?;
+
+                match 11
+                    - weighted_sum(
+                        n,
+                        &[
+                            7, 9, 10, 5, 8, 4, 2,
+                        ],
+                    )
+                    .unwrap_or_default()
+                        % 11
+                {
+                    10 => false,
+                    11 => {
+                        digit(
+                            n, 7,
+                        ) == 0
+                    }
+                    check => {
+                        check
+                            == digit(
+                                n, 7,
+                            )
+                    }
+                }
+            }
+            CountryCode::Fr => {
+                format(
+                    n.len() == 11
+                        && all_digits(&n[2..])
+                        && n[..2]
+                            .chars()
+                            .all(|x| x.is_ascii_alphanumeric()),
+                )?;
+
+                // keys with letters are assigned to new subjects without a public algorithm
+                luhn(&n[2..])
+                    && (!all_digits(&n[..2])
+                        || value(&n[..2]) == (12 + 3 * (value(&n[2..]) % 97)) % 97)
+            }
+            CountryCode::Gr => {
+                format(
+                    digits(
+                        n,
+                        &[9],
+                    ),
+                )?;
+
+                weighted_sum(
+                    n,
+                    &[
+                        256, 128, 64, 32, 16, 8, 4, 2,
+                    ],
+                )
+                .unwrap_or_default()
+                    % 11
+                    % 10
+                    == digit(
+                        n, 8,
+                    )
+            }
+            CountryCode::Hr => {
+                format(
+                    digits(
+                        n,
+                        &[11],
+                    ),
+                )?;
+
+                mod_11_10(n)
+            }
+            CountryCode::Hu => {
+                format(
+                    digits(
+                        n,
+                        &[8],
+                    ),
+                )?;
+
+                weighted_sum(
+                    n,
+                    &[
+                        9, 7, 3, 1, 9, 7, 3, 1,
+                    ],
+                )
+                .unwrap_or_default()
+                .is_multiple_of(10)
+            }
+            CountryCode::Ie => {
+                format(
+                    (8..=9).contains(&n.len())
+                        && n.starts_with(|x: char| x.is_ascii_digit())
+                        && n.chars()
+                            .all(|x| x.is_ascii_alphanumeric() || x == '+' || x == '*'),
+                )?;
+
+                true
+            }
+            CountryCode::It => {
+                format(
+                    digits(
+                        n,
+                        &[11],
+                    ),
+                )?;
+
+                luhn(n)
+            }
+            CountryCode::Lt => {
+                format(
+                    digits(
+                        n,
+                        &[
+                            9, 12,
+                        ],
+                    ),
+                )?;
+
+                true
+            }
+            CountryCode::Lu => {
+                format(
+                    digits(
+                        n,
+                        &[8],
+                    ),
+                )?;
+
+                value(&n[..6]) % 89 == value(&n[6..])
+            }
+            CountryCode::Lv => {
+                format(
+                    digits(
+                        n,
+                        &[11],
+                    ),
+                )?;
+
+                true
+            }
+            CountryCode::Mt => {
+                format(
+                    digits(
+                        n,
+                        &[8],
+                    ) && !n.starts_with('0'),
+                )?;
+
+                37 - weighted_sum(
+                    n,
+                    &[
+                        3, 4, 6, 7, 8, 9,
+                    ],
+                )
+                .unwrap_or_default()
+                    % 37
+                    == value(&n[6..]) as u32
+            }
+            CountryCode::Nl => {
+                format(
+                    n.len() == 12
+                        && all_digits(&n[..9])
+                        && &n[9..10] == "B"
+                        && all_digits(&n[10..]),
+                )?;
+
+                // numbers of sole proprietors issued since 2020 use MOD 97-10
+                weighted_sum(
+                    n,
+                    &[
+                        9, 8, 7, 6, 5, 4, 3, 2,
+                    ],
+                )
+                .unwrap_or_default()
+                    % 11
+                    == digit(
+                        n, 8,
+                    )
+                    || mod_97(
+                        &format!(
+                            "NL{}",
+                            n
+                        ),
+                    )
+            }
+            CountryCode::Pl => {
+                format(
+                    digits(
+                        n,
+                        &[10],
+                    ),
+                )?;
+
+                weighted_sum(
+                    n,
+                    &[
+                        6, 5, 7, 2, 3, 4, 5, 6, 7,
+                    ],
+                )
+                .unwrap_or_default()
+                    % 11
+                    == digit(
+                        n, 9,
+                    )
+            }
+            CountryCode::Pt => {
+                format(
+                    digits(
+                        n,
+                        &[9],
+                    ),
+                )?;
+
+                match 11
+                    - weighted_sum(
+                        n,
+                        &[
+                            9, 8, 7, 6, 5, 4, 3, 2,
+                        ],
+                    )
+                    .unwrap_or_default()
+                        % 11
+                {
+                    10 | 11 => {
+                        digit(
+                            n, 8,
+                        ) == 0
+                    }
+                    check => {
+                        check
+                            == digit(
+                                n, 8,
+                            )
+                    }
+                }
+            }
+            CountryCode::Ro => {
+                format(
+                    digits(
+                        n,
+                        &[
+                            2, 3, 4, 5, 6, 7, 8, 9, 10,
+                        ],
+                    ) && !n.starts_with('0'),
+                )?;
+
+                const WEIGHTS: [u32; 9] = [
+                    7, 5, 3, 2, 1, 7, 5, 3, 2,
+                ];
+
+                weighted_sum(
+                    n,
+                    &WEIGHTS[WEIGHTS.len() + 1 - n.len()..],
+                )
+                .unwrap_or_default()
+                    * 10
+                    % 11
+                    % 10
+                    == digit(
+                        n,
+                        n.len() - 1,
+                    )
+            }
+            CountryCode::Se => {
+                format(
+                    digits(
+                        n,
+                        &[12],
+                    ) && n.ends_with("01"),
+                )?;
+
+                luhn(&n[..10])
+            }
+            CountryCode::Si => {
+                format(
+                    digits(
+                        n,
+                        &[8],
+                    ) && !n.starts_with('0'),
+                )?;
+
+                match 11
+                    - weighted_sum(
+                        n,
+                        &[
+                            8, 7, 6, 5, 4, 3, 2,
+                        ],
+                    )
+                    .unwrap_or_default()
+                        % 11
+                {
+                    11 => false,
+                    10 => {
+                        digit(
+                            n, 7,
+                        ) == 0
+                    }
+                    check => {
+                        check
+                            == digit(
+                                n, 7,
+                            )
+                    }
+                }
+            }
+            CountryCode::Sk => {
+                format(
+                    digits(
+                        n,
+                        &[10],
+                    ) && !n.starts_with('0')
+                        && matches!(
+                            n.as_bytes()[2],
+                            b'2' | b'3' | b'4' | b'7' | b'8' | b'9'
+                        ),
+                )?;
+
+                value(n).is_multiple_of(11)
+            }
+            _ => return Err(VatNumberError::UnknownCountry(country.to_string())),
+        };
+
+        match checksum {
+            true => {
+                Ok(
+                    Self {
+                        country,
+                        number,
+                    },
+                )
+            }
+            false => Err(VatNumberError::InvalidChecksum(country)),
+        }
+    }
+
+    /// Returns the country which issued the number.
+    pub fn country(&self) -> CountryCode {
+        self.country
+    }
+
+    /// Returns the number without the country prefix.
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+
+    /// Returns the prefix of the number, the country code except for Greece.
+    pub fn prefix(&self) -> &'static str {
+        match self.country {
+            CountryCode::Gr => "EL",
+            country => country.code(),
+        }
+    }
+}
+
+impl Display for VatNumber {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}",
+            self.prefix(),
+            self.number
+        )
+    }
+}
+
+impl FromStr for VatNumber {
+    type Err = VatNumberError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+
+        let prefix = value
+            .get(..2)
+            .ok_or_else(|| VatNumberError::UnknownCountry(value.to_string()))?;
+
+        let country = match prefix.to_uppercase() {
+            prefix if prefix == "EL" => CountryCode::Gr,
+            prefix => {
+                prefix
+                    .parse()
+                    .or(Err(VatNumberError::UnknownCountry(prefix)))?
+            }
+        };
+
+        Self::new(
+            country,
+            &value[2..],
+        )
+    }
+}
+
+impl Serialize for VatNumber {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for VatNumber {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_vat_numbers() {
+        for value in [
+            "ATU13585627",
+            "BE0403019261",
+            "BG175074752",
+            "CY10259033P",
+            "CZ27082440",
+            "CZ 251 23 891",
+            "CZ640903926",
+            "CZ699001182",
+            "CZ7103192745",
+            "CZ510101001",
+            "DE136695976",
+            "DK13585628",
+            "EE100931558",
+            "EL094259216",
+            "ESB58378431",
+            "FI20774740",
+            "FR40303265045",
+            "HR33392005961",
+            "HU12892312",
+            "IE6433435F",
+            "IT00743110157",
+            "LT119511515",
+            "LU15027442",
+            "LV40003521600",
+            "MT11679112",
+            "NL004495445B01",
+            "NL000099998B57",
+            "PL8567346215",
+            "PT501964843",
+            "RO18547290",
+            "SE123456789701",
+            "SI50223054",
+            "SK2022749619",
+        ] {
+            let number: VatNumber = value
+                .parse()
+                .unwrap_or_else(
+                    |e| {
+                        panic!(
+                            "{}: {}",
+                            value, e
+                        )
+                    },
+                );
+
+            assert_eq!(
+                number.to_string(),
+                value.replace(' ', "")
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_vat_numbers() {
+        assert!(
+            matches!(
+                "CZ1234".parse::<VatNumber>(),
+                Err(VatNumberError::InvalidFormat(CountryCode::Cz))
+            )
+        );
+        assert!(
+            matches!(
+                "CZ27082441".parse::<VatNumber>(),
+                Err(VatNumberError::InvalidChecksum(CountryCode::Cz))
+            )
+        );
+        assert!(
+            matches!(
+                "CZ7113192745".parse::<VatNumber>(),
+                Err(VatNumberError::InvalidChecksum(CountryCode::Cz))
+            )
+        );
+        assert!(
+            matches!(
+                "SK2022749618".parse::<VatNumber>(),
+                Err(VatNumberError::InvalidChecksum(CountryCode::Sk))
+            )
+        );
+        assert!(
+            matches!(
+                "DE136695975".parse::<VatNumber>(),
+                Err(VatNumberError::InvalidChecksum(CountryCode::De))
+            )
+        );
+        assert!(
+            matches!(
+                "ATU1358562".parse::<VatNumber>(),
+                Err(VatNumberError::InvalidFormat(CountryCode::At))
+            )
+        );
+        assert!(
+            matches!(
+                "XX123456789".parse::<VatNumber>(),
+                Err(VatNumberError::UnknownCountry(_))
+            )
+        );
+        assert!(
+            matches!(
+                "US123456789".parse::<VatNumber>(),
+                Err(VatNumberError::UnknownCountry(_))
+            )
+        );
+    }
+
+    #[test]
+    fn test_serde() {
+        let number: VatNumber = serde_json::from_str("\"EL094259216\"").unwrap();
+
+        assert_eq!(
+            number.country(),
+            CountryCode::Gr
+        );
+        assert_eq!(
+            number.number(),
+            "094259216"
+        );
+        assert_eq!(
+            serde_json::to_string(&number).unwrap(),
+            "\"EL094259216\""
+        );
+        assert!(serde_json::from_str::<VatNumber>("\"CZ1234\"").is_err());
+    }
+}
@@ -0,0 +1,151 @@
+use reqwest::header::CONTENT_TYPE;
+
+use super::client::parse_check;
+use super::Error;
+use super::ViesCheck;
+use super::ViesConfig;
+use crate::vat_number::VatNumber;
+
+/// Blocking client of the VIES VAT number validation
+#[derive(Debug, Clone)]
+pub struct ViesClient {
+    config: ViesConfig,
+    client: reqwest::blocking::Client,
+}
+
+impl ViesClient {
+    pub fn new(config: ViesConfig) -> Result<Self, Error> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout)
+            .build()
+            .map_err(Error::TransportError)?;
+
+        Ok(
+            Self {
+                config,
+                client,
+            },
+        )
+    }
+
+    /// Validates the VAT number in VIES
+    pub fn check(
+        &self,
+        number: &VatNumber,
+    ) -> Result<ViesCheck, Error> {
+        let url = self
+            .config
+            .check_url();
+        let request = self
+            .config
+            .request_body(number)?;
+
+        let body = self
+            .config
+            .retry()
+            .send_blocking(
+                || {
+                    self.client
+                        .post(&url)
+                        .header(
+                            CONTENT_TYPE,
+                            "application/json",
+                        )
+                        .body(request.clone())
+                },
+                None,
+                None,
+            )?;
+
+        parse_check(
+            number, &body,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::tests::serve;
+    use crate::vies::tests::VALID;
+
+    #[test]
+    fn test_check() {
+        let client = ViesClient::new(
+            ViesConfig {
+                base_url: serve(
+                    vec![
+                        (
+                            200, VALID,
+                        ),
+                    ],
+                ),
+                requester: Some(
+                    "CZ25596641"
+                        .parse()
+                        .unwrap(),
+                ),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let check = client
+            .check(
+                &"CZ27082440"
+                    .parse()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        assert!(check.valid);
+        assert_eq!(
+            check
+                .name
+                .as_deref(),
+            Some("Alza.cz a.s.")
+        );
+        assert_eq!(
+            check
+                .consultation_number
+                .as_deref(),
+            Some("WAPIAAAAY4LsrHfT")
+        );
+        assert_eq!(
+            check
+                .request_date
+                .map(|x| x.to_rfc3339()),
+            Some("2024-03-01T10:15:30.123+00:00".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unavailable() {
+        let client = ViesClient::new(
+            ViesConfig {
+                base_url: serve(
+                    vec![
+                        (
+                            200,
+                            r#"{"actionSucceed": false, "errorWrappers": [{"error": "MS_UNAVAILABLE"}]}"#,
+                        ),
+                    ],
+                ),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert!(
+            matches!(
+                client.check(
+                    &"CZ27082440"
+                        .parse()
+                        .unwrap()
+                ),
+                Err(Error::ServiceError(error)) if error == "MS_UNAVAILABLE"
+            )
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Error;
+use super::ViesCheck;
+use super::ViesConfig;
+use crate::http;
+use crate::http::Retry;
+use crate::vat_number::VatNumber;
+
+/// Body of the `check-vat-number` request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ViesRequest<'a> {
+    country_code: &'a str,
+    vat_number: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requester_member_state_code: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requester_number: Option<&'a str>,
+}
+
+/// Response of the `check-vat-number` endpoint
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ViesResponse {
+    #[serde(default)]
+    valid: bool,
+    request_date: Option<DateTime<Utc>>,
+    request_identifier: Option<String>,
+    name: Option<String>,
+    address: Option<String>,
+    /// Present when the request failed, e.g. `MS_UNAVAILABLE` if the member state is offline
+    #[serde(default)]
+    error_wrappers: Vec<ViesErrorWrapper>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ViesErrorWrapper {
+    error: String,
+}
+
+impl ViesConfig {
+    pub(super) fn check_url(&self) -> String {
+        http::url(
+            &self.base_url,
+            "check-vat-number",
+        )
+    }
+
+    pub(super) fn request_body(
+        &self,
+        number: &VatNumber,
+    ) -> Result<String, Error> {
+        serde_json::to_string(
+            &ViesRequest {
+                country_code: number.prefix(),
+                vat_number: number.number(),
+                requester_member_state_code: self
+                    .requester
+                    .as_ref()
+                    .map(VatNumber::prefix),
+                requester_number: self
+                    .requester
+                    .as_ref()
+                    .map(VatNumber::number),
+            },
+        )
+        .map_err(Error::JsonError)
+    }
+
+    pub(super) fn retry(&self) -> Retry {
+        Retry {
+            max_retries: self.max_retries,
+            backoff: self.backoff,
+            max_retry_after: self.max_retry_after,
+        }
+    }
+}
+
+/// VIES fills the data a member state doesn't publish with `---`
+fn published(value: Option<String>) -> Option<String> {
+    value
+        .map(
+            |x| {
+                x.trim()
+                    .to_string()
+            },
+        )
+        .filter(|x| !x.is_empty() && x != "---")
+}
+
+pub(super) fn parse_check(
+    number: &VatNumber,
+    body: &str,
+) -> Result<ViesCheck, Error> {
+    let response: ViesResponse = serde_json::from_str(body).map_err(Error::JsonError)?;
+
+    if let Some(error) = response
+        .error_wrappers
+        .into_iter()
+        .next()
+    {
+        return Err(Error::ServiceError(error.error));
+    }
+
+    Ok(
+        ViesCheck {
+            vat_number: number.clone(),
+            valid: response.valid,
+            request_date: response.request_date,
+            name: published(response.name),
+            address: published(response.address),
+            consultation_number: published(response.request_identifier),
+        },
+    )
+}
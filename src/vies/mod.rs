@@ -0,0 +1,189 @@
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::entity::eu::Entity;
+use crate::vat_number::VatNumber;
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(
+    any(
+        feature = "blocking",
+        feature = "async"
+    )
+)]
+mod client;
+#[cfg(feature = "async")]
+pub mod nonblocking;
+
+#[cfg(feature = "blocking")]
+pub use blocking::ViesClient;
+#[cfg(feature = "async")]
+pub use nonblocking::AsyncViesClient;
+
+pub use crate::http::Error;
+
+/// Base URL of the REST API of the VAT Information Exchange System (VIES)
+pub const DEFAULT_BASE_URL: &str = "https://ec.europa.eu/taxation_customs/vies/rest-api";
+
+/// Result of the validation of a VAT number in VIES.
+///
+/// VIES only answers whether the number is valid at the time of the request,
+/// store the check made on the invoice date to prove the validity for an intra-community supply.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ViesCheck {
+    pub vat_number: VatNumber,
+    pub valid: bool,
+    /// Time of the validation recorded by VIES, `None` when VIES didn't report it
+    pub request_date: Option<DateTime<Utc>>,
+    /// Registered name, some member states don't publish it
+    pub name: Option<String>,
+    /// Registered address, some member states don't publish it
+    pub address: Option<String>,
+    /// Consultation number proving the validation, returned only when [`ViesConfig::requester`] is set
+    pub consultation_number: Option<String>,
+}
+
+/// Lowercase letters and digits of the value, used to compare names and addresses
+fn normalize(value: &str) -> String {
+    value
+        .chars()
+        .filter(|x| x.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+impl ViesCheck {
+    /// Checks that the number is valid and belongs to the entity, e.g. one fetched from ARES.
+    ///
+    /// Names are compared ignoring case, spaces and punctuation, the address has to contain
+    /// the postal code of the entity. Data the member state doesn't publish isn't compared.
+    pub fn matches(
+        &self,
+        entity: &Entity,
+    ) -> bool {
+        self.valid
+            && entity
+                .vat_number
+                .as_ref()
+                == Some(&self.vat_number)
+            && self
+                .name
+                .as_ref()
+                .is_none_or(|name| normalize(name) == normalize(&entity.name))
+            && self
+                .address
+                .as_ref()
+                .is_none_or(
+                    |address| {
+                        normalize(address).contains(
+                            &normalize(
+                                &entity
+                                    .address
+                                    .postal_code,
+                            ),
+                        )
+                    },
+                )
+    }
+}
+
+/// Configuration of the VIES client
+#[derive(Debug, Clone)]
+pub struct ViesConfig {
+    pub base_url: String,
+    /// VAT number of the one asking, VIES returns the consultation number only when it's set
+    pub requester: Option<VatNumber>,
+    /// Timeout of the whole request
+    pub timeout: Duration,
+    pub connect_timeout: Duration,
+    /// How many times a request is retried after a 429 or 5xx response
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled with every next one
+    pub backoff: Duration,
+    /// Longest `Retry-After` the client waits for, a longer one fails with `Error::RateLimited`
+    pub max_retry_after: Duration,
+}
+
+impl Default for ViesConfig {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            requester: None,
+            timeout: Duration::from_secs(30),
+            connect_timeout: Duration::from_secs(5),
+            max_retries: 3,
+            backoff: Duration::from_millis(500),
+            max_retry_after: Duration::from_secs(60),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::Address;
+    use crate::country::CountryCode;
+
+    #[cfg(feature = "blocking")]
+    pub(crate) const VALID: &str = r#"{
+        "countryCode": "CZ",
+        "vatNumber": "27082440",
+        "requestDate": "2024-03-01T10:15:30.123Z",
+        "valid": true,
+        "requestIdentifier": "WAPIAAAAY4LsrHfT",
+        "name": "Alza.cz a.s.",
+        "address": "Jankovcova 1522/53\nPRAHA 7 - HOLEŠOVICE\n170 00  PRAHA 7",
+        "traderName": "---",
+        "traderStreet": "---",
+        "traderPostalCode": "---",
+        "traderCity": "---",
+        "traderCompanyType": "---"
+    }"#;
+
+    #[test]
+    fn test_matches() {
+        let check = ViesCheck {
+            vat_number: "CZ27082440"
+                .parse()
+                .unwrap(),
+            valid: true,
+            request_date: Some(Utc::now()),
+            name: Some("ALZA.CZ a.s.".to_string()),
+            address: Some("Jankovcova 1522/53\nPRAHA 7 - HOLEŠOVICE\n170 00  PRAHA 7".to_string()),
+            consultation_number: None,
+        };
+
+        let mut entity = Entity::new(
+            "27082440"
+                .parse()
+                .unwrap(),
+            "Alza.cz a.s.",
+            Address::new(
+                CountryCode::Cz,
+                "Praha 7",
+                "Jankovcova",
+                "17000",
+                "1522/53",
+            ),
+            Some(
+                "CZ27082440"
+                    .parse()
+                    .unwrap(),
+            ),
+        );
+
+        assert!(check.matches(&entity));
+
+        entity.name = "Alza s.r.o.".to_string();
+        assert!(!check.matches(&entity));
+
+        entity.name = "Alza.cz a.s.".to_string();
+        entity.vat_number = None;
+        assert!(!check.matches(&entity));
+    }
+}
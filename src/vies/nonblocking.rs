@@ -0,0 +1,66 @@
+use reqwest::header::CONTENT_TYPE;
+
+use super::client::parse_check;
+use super::Error;
+use super::ViesCheck;
+use super::ViesConfig;
+use crate::vat_number::VatNumber;
+
+/// Asynchronous client of the VIES VAT number validation, for use inside a tokio runtime
+#[derive(Debug, Clone)]
+pub struct AsyncViesClient {
+    config: ViesConfig,
+    client: reqwest::Client,
+}
+
+impl AsyncViesClient {
+    pub fn new(config: ViesConfig) -> Result<Self, Error> {
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout)
+            .build()
+            .map_err(Error::TransportError)?;
+
+        Ok(
+            Self {
+                config,
+                client,
+            },
+        )
+    }
+
+    /// Validates the VAT number in VIES
+    pub async fn check(
+        &self,
+        number: &VatNumber,
+    ) -> Result<ViesCheck, Error> {
+        let url = self
+            .config
+            .check_url();
+        let request = self
+            .config
+            .request_body(number)?;
+
+        let body = self
+            .config
+            .retry()
+            .send_async(
+                || {
+                    self.client
+                        .post(&url)
+                        .header(
+                            CONTENT_TYPE,
+                            "application/json",
+                        )
+                        .body(request.clone())
+                },
+                None,
+                None,
+            )
+            .await?;
+
+        parse_check(
+            number, &body,
+        )
+    }
+}